@@ -0,0 +1,51 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use danubia::character::{Class, ElementMatrix, FactionBuilder, Retaliation, Succession, UnitBuilder, UnitStatistics};
+use danubia::common::{Information, Scene};
+use danubia::map::{Grid, Location, Mover, Terrain, TileBuilder, ZoneOfControl};
+use std::hint::black_box;
+use std::rc::Rc;
+
+const LENGTH: usize = 100;
+
+fn generate_grid () -> Grid {
+    let terrains: Vec<Terrain> = vec! [
+        Terrain::new (None, 1, 0, 0, 0, 0, 0, None, Information::new ("Plains", &["Open ground"])),
+        Terrain::new (None, 2, 0, 0, 0, 0, 0, None, Information::new ("Forest", &["Woodland"])),
+    ];
+    let classes: Vec<Class> = vec! [Class::new (0, UnitStatistics::new (1000, 1000, 1000, 20, 20, 20, 10, 1000), &[], [false, false, false], &[], &[], None)];
+    let faction_builders: Vec<FactionBuilder> = vec! [FactionBuilder::new (0, &[], Succession::Organisation)];
+    let unit_builders: Vec<UnitBuilder> = vec! [UnitBuilder::new (0, 0, &[], None, &[], 0, None, None, [0; 5])];
+    let tile_builders: Vec<Vec<TileBuilder>> = (0 .. LENGTH).map (|i: usize|
+        (0 .. LENGTH).map (|j: usize| TileBuilder::new ((i + j) % 2, 0, None)).collect ()
+    ).collect ();
+    let unit_locations: Vec<Option<Location>> = vec! [None];
+    let scene: Scene = Scene::new (Vec::new (), Vec::new (), Vec::new (), terrains, Vec::new (), Vec::new (), Vec::new (), Vec::new (), classes, faction_builders, unit_builders, tile_builders, unit_locations, Vec::new (), Vec::new (), Vec::new (), Vec::new (), Retaliation::default (), ZoneOfControl::default (), ElementMatrix::default (), Vec::new (), Vec::new ());
+    let mut grid: Grid = Grid::new (Rc::new (scene));
+
+    grid.place_unit (0, (LENGTH / 2, LENGTH / 2));
+
+    grid
+}
+
+fn grid_find_unit_reachable (c: &mut Criterion) {
+    let grid: Grid = generate_grid ();
+    let mover: Mover = Mover::new (0, 0, &[], &[]);
+
+    for mov in [10, 50, u16::MAX] {
+        c.bench_function (&format! ("grid_find_unit_reachable_{}", mov), |b|
+            b.iter (|| grid.find_unit_reachable (black_box (&mover), black_box (mov)))
+        );
+    }
+}
+
+fn grid_find_path (c: &mut Criterion) {
+    let grid: Grid = generate_grid ();
+    let mover: Mover = Mover::new (0, 0, &[], &[]);
+
+    c.bench_function ("grid_find_path", |b|
+        b.iter (|| grid.find_path (black_box (&mover), black_box (&(0, 0)), black_box (u16::MAX)))
+    );
+}
+
+criterion_group! (benches, grid_find_unit_reachable, grid_find_path);
+criterion_main! (benches);
@@ -0,0 +1,47 @@
+use danubia::common::Scene;
+use danubia::controller::{Computer, Controller};
+use danubia::system::{Replay, Report, Simulation};
+use std::env;
+use std::error::Error;
+use std::sync::mpsc::{self, Receiver, Sender};
+
+const NUMBER_TURNS_MAX: usize = 1000;
+
+// headless [scene] [number_turns_max]
+// headless --replay <replay> [scene]
+fn main () -> Result<(), Box<dyn Error>> {
+    let args: Vec<String> = env::args ().skip (1).collect ();
+    // Headless runs only report the outcome, so the log is dropped
+    let (sender, _): (Sender<String>, Receiver<String>) = mpsc::channel ();
+    let report: Report = if args.first ().map (String::as_str) == Some ("--replay") {
+        let replay: Replay = match args.get (1) {
+            Some (path) => Replay::load (path)?,
+            None => return Err (Box::from ("Expected a replay path after --replay")),
+        };
+        let scene: Scene = match args.get (2) {
+            Some (path) => Scene::load (path)?,
+            None => Scene::default (),
+        };
+
+        Simulation::replay (scene, sender, &replay)?
+    } else {
+        let scene: Scene = match args.first () {
+            Some (path) => Scene::load (path)?,
+            None => Scene::default (),
+        };
+        let number_turns_max: usize = match args.get (1) {
+            Some (number_turns_max) => number_turns_max.parse ()?,
+            None => NUMBER_TURNS_MAX,
+        };
+        let controllers: Vec<Box<dyn Controller>> = scene.faction_builders_iter ().map (|_|
+            Box::new (Computer) as Box<dyn Controller>
+        ).collect ();
+        let mut simulation: Simulation = Simulation::new (scene, sender, controllers, number_turns_max)?;
+
+        simulation.run ()
+    };
+
+    print! ("{}", report);
+
+    Ok (())
+}
@@ -0,0 +1,127 @@
+use super::{Element, UnitStatistics};
+use crate::common::ID;
+use serde::{Deserialize, Serialize};
+
+pub const MULTIPLIER_BASE: u16 = 100; // 100%, shared by every percentage in a scene
+
+#[derive (Debug)]
+#[derive (Clone, Copy)]
+#[derive (PartialEq)]
+#[derive (Deserialize, Serialize)]
+pub enum Requirement {
+    Level (u8),
+    Weapon (ID), // equipped weapon, as weapons are the only items units carry
+}
+
+/*
+ * Archetype shared by units
+ * Movement cost multipliers are percentages, and 0 makes a terrain impassable
+ */
+#[derive (Debug)]
+#[derive (Clone)]
+#[derive (Deserialize, Serialize)]
+pub struct Class {
+    id: ID,
+    statistics: UnitStatistics,
+    weapon_ids: Vec<ID>, // allowed weapons
+    magics_usable: [bool; Element::Length as usize],
+    #[serde (default)]
+    multipliers_terrain: Vec<(ID, u16)>, // terrain, multiplier
+    #[serde (default)]
+    promotions: Vec<(ID, Requirement)>, // class, requirement
+    #[serde (default)]
+    affinity: Option<Element>,
+}
+
+impl Class {
+    pub fn new (id: ID, statistics: UnitStatistics, weapon_ids: &[ID], magics_usable: [bool; Element::Length as usize], multipliers_terrain: &[(ID, u16)], promotions: &[(ID, Requirement)], affinity: Option<Element>) -> Self {
+        let weapon_ids: Vec<ID> = weapon_ids.to_vec ();
+        let multipliers_terrain: Vec<(ID, u16)> = multipliers_terrain.to_vec ();
+        let promotions: Vec<(ID, Requirement)> = promotions.to_vec ();
+
+        Self { id, statistics, weapon_ids, magics_usable, multipliers_terrain, promotions, affinity }
+    }
+
+    pub fn is_allowed (&self, weapon_id: &ID) -> bool {
+        self.weapon_ids.contains (weapon_id)
+    }
+
+    pub fn find_multiplier (&self, terrain_id: &ID) -> u16 {
+        self.multipliers_terrain.iter ()
+                .find (|(t, _): &&(ID, u16)| t == terrain_id)
+                .map_or (MULTIPLIER_BASE, |(_, m): &(ID, u16)| *m)
+    }
+
+    pub fn find_promotions (&self, level: u8, weapon_ids: &[ID]) -> Vec<ID> {
+        self.promotions.iter ().filter_map (|(c, r): &(ID, Requirement)| {
+            let is_met: bool = match r {
+                Requirement::Level (l) => level >= *l,
+                Requirement::Weapon (w) => weapon_ids.contains (w),
+            };
+
+            if is_met {
+                Some (*c)
+            } else {
+                None
+            }
+        }).collect ()
+    }
+
+    pub fn get_id (&self) -> ID {
+        self.id
+    }
+
+    pub fn get_statistics (&self) -> &UnitStatistics {
+        &self.statistics
+    }
+
+    pub fn get_weapon_ids (&self) -> &[ID] {
+        &self.weapon_ids
+    }
+
+    pub fn get_magics_usable (&self) -> &[bool; Element::Length as usize] {
+        &self.magics_usable
+    }
+
+    pub fn get_multipliers_terrain (&self) -> &[(ID, u16)] {
+        &self.multipliers_terrain
+    }
+
+    pub fn get_promotions (&self) -> &[(ID, Requirement)] {
+        &self.promotions
+    }
+
+    pub fn get_affinity (&self) -> Option<Element> {
+        self.affinity
+    }
+}
+
+#[cfg (test)]
+mod tests {
+    use super::*;
+    use crate::tests::generate_scene;
+
+    #[test]
+    fn class_find_multiplier () {
+        let scene = generate_scene ();
+        let class_0 = scene.get_class (&0);
+        let class_1 = scene.get_class (&1);
+
+        assert_eq! (class_0.find_multiplier (&1), MULTIPLIER_BASE);
+        assert_eq! (class_1.find_multiplier (&0), MULTIPLIER_BASE);
+        assert_eq! (class_1.find_multiplier (&1), 200);
+    }
+
+    #[test]
+    fn class_find_promotions () {
+        let scene = generate_scene ();
+        let class_0 = scene.get_class (&0);
+
+        assert! (class_0.find_promotions (0, &[0]).is_empty ());
+        // Test level requirement
+        assert_eq! (class_0.find_promotions (5, &[0]), vec![1]);
+        // Test item requirement
+        assert_eq! (class_0.find_promotions (0, &[1]), vec![3]);
+        assert_eq! (class_0.find_promotions (5, &[0, 1]), vec![1, 3]);
+    }
+}
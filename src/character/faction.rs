@@ -1,18 +1,43 @@
 use super::Unit;
 use crate::collections::OuterJoinMap;
 use crate::common::{ID, ID_UNINITIALISED};
-use std::collections::HashSet;
+use crate::map::Location;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 
+/*
+ * Rule for choosing a fallen leader's successor from their followers
+ * Heir falls back to Organisation if the designated heir is not a surviving follower
+ */
 #[derive (Debug)]
+#[derive (Clone, Copy)]
+#[derive (PartialEq)]
+#[derive (Default)]
+#[derive (Deserialize, Serialize)]
+pub enum Succession {
+    Heir, // designated heir in UnitBuilder
+    #[default]
+    Organisation, // highest ORG follower
+    Nearest, // nearest follower to the fallen leader
+}
+
+#[derive (Debug)]
+#[derive (Clone)]
+#[derive (Deserialize, Serialize)]
 pub struct Faction {
     id: ID,
     member_ids: HashSet<ID>,
     leader_followers: OuterJoinMap<ID, ID>,
-    allies: &'static [ID],
+    allies: Vec<ID>,
+    #[serde (default)]
+    succession: Succession,
+    locations_visible: HashSet<Location>,
+    unit_locations_seen: HashMap<ID, Location>, // last seen location of each enemy
 }
 
 impl Faction {
-    pub fn new (id: ID, allies: &'static [ID], units: &[Unit]) -> Self {
+    pub fn new (id: ID, allies: &[ID], succession: Succession, units: &[Unit]) -> Self {
+        let allies: Vec<ID> = allies.to_vec ();
         let mut member_ids: HashSet<ID> = HashSet::new ();
         let mut leader_followers: OuterJoinMap<ID, ID> = OuterJoinMap::new ();
 
@@ -29,7 +54,10 @@ impl Faction {
             }
         }
 
-        Self { id, member_ids, leader_followers, allies }
+        let locations_visible: HashSet<Location> = HashSet::new ();
+        let unit_locations_seen: HashMap<ID, Location> = HashMap::new ();
+
+        Self { id, member_ids, leader_followers, allies, succession, locations_visible, unit_locations_seen }
     }
 
     pub fn is_member (&self, unit_id: &ID) -> bool {
@@ -40,6 +68,16 @@ impl Faction {
         *faction_id == self.id || self.allies.contains (faction_id)
     }
 
+    pub fn set_ally (&mut self, faction_id: ID, is_ally: bool) {
+        if is_ally {
+            if !self.is_ally (&faction_id) {
+                self.allies.push (faction_id);
+            }
+        } else {
+            self.allies.retain (|a: &ID| *a != faction_id);
+        }
+    }
+
     pub fn add_follower (&mut self, follower_id: ID, leader_id: ID) -> bool {
         if leader_id < ID_UNINITIALISED {
             self.leader_followers.insert ((leader_id, follower_id))
@@ -52,24 +90,88 @@ impl Faction {
         self.leader_followers.remove (unit_id)
     }
 
+    pub fn get_allies (&self) -> &[ID] {
+        &self.allies
+    }
+
+    pub fn get_succession (&self) -> Succession {
+        self.succession
+    }
+
+    // Followers without a new leader lead themselves
+    pub fn reroute_followers (&mut self, leader_id: &ID, leader_id_new: Option<ID>) -> Vec<ID> {
+        let mut follower_ids: Vec<ID> = self.leader_followers.get_first (leader_id)
+                .map_or (Vec::new (), |f: &HashSet<ID>| f.iter ().copied ().filter (|f: &ID| f != leader_id).collect ());
+
+        // Sorted for determinism
+        follower_ids.sort_unstable ();
+
+        for follower_id in follower_ids.iter () {
+            self.leader_followers.replace (*follower_id, leader_id_new.unwrap_or (*follower_id));
+        }
+
+        follower_ids
+    }
+
     pub fn get_followers (&self, unit_id: &ID) -> &HashSet<ID> {
         self.leader_followers.get_first (unit_id)
                 .unwrap_or_else (|| panic! ("Followers not found for unit {}", unit_id))
     }
+
+    pub fn update_visibility (&mut self, locations_visible: HashSet<Location>, enemy_locations: &[(ID, Location)]) {
+        // Enemies aren't where they were last seen if that location is visible now, and the dead aren't anywhere
+        self.unit_locations_seen.retain (|u: &ID, l: &mut Location|
+            !locations_visible.contains (l) && enemy_locations.iter ().any (|(e, _): &(ID, Location)| e == u)
+        );
+
+        for (enemy_id, location) in enemy_locations {
+            if locations_visible.contains (location) {
+                self.unit_locations_seen.insert (*enemy_id, *location);
+            }
+        }
+
+        self.locations_visible = locations_visible;
+    }
+
+    pub fn is_visible (&self, location: &Location) -> bool {
+        self.locations_visible.contains (location)
+    }
+
+    pub fn get_locations_visible (&self) -> &HashSet<Location> {
+        &self.locations_visible
+    }
+
+    pub fn get_unit_location_seen (&self, unit_id: &ID) -> Option<&Location> {
+        self.unit_locations_seen.get (unit_id)
+    }
 }
 
 #[derive (Debug)]
+#[derive (Clone)]
+#[derive (Deserialize, Serialize)]
 pub struct FactionBuilder {
     id: ID,
-    allies: &'static [ID],
+    allies: Vec<ID>,
+    #[serde (default)]
+    succession: Succession,
 }
 
 impl FactionBuilder {
-    pub const fn new (id: ID, allies: &'static [ID]) -> Self {
-        Self { id, allies }
+    pub fn new (id: ID, allies: &[ID], succession: Succession) -> Self {
+        let allies: Vec<ID> = allies.to_vec ();
+
+        Self { id, allies, succession }
     }
 
     pub fn build (&self, units: &[Unit]) -> Faction {
-        Faction::new (self.id, self.allies, units)
+        Faction::new (self.id, &self.allies, self.succession, units)
+    }
+
+    pub fn get_id (&self) -> ID {
+        self.id
+    }
+
+    pub fn get_allies (&self) -> &[ID] {
+        &self.allies
     }
 }
@@ -1,11 +1,13 @@
-use super::Tool;
+use super::{MULTIPLIER_BASE, Tool};
 use crate::common::{ID, Scene, Target};
 use crate::dynamic::{Appliable, AppliableKind, Applier};
 use crate::map::Area;
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
 #[derive (Debug)]
 #[derive (Clone, Copy)]
+#[derive (Deserialize, Serialize)]
 pub enum Element {
     Matter,
     Dark,
@@ -13,8 +15,47 @@ pub enum Element {
     Length,
 }
 
+/*
+ * Percentages that scale magic by its element (row) against the element of whatever it hits (column)
+ * Units and terrains without an element are unaffected
+ */
 #[derive (Debug)]
 #[derive (Clone, Copy)]
+#[derive (PartialEq)]
+#[derive (Deserialize, Serialize)]
+pub struct ElementMatrix ([[u16; Element::Length as usize]; Element::Length as usize]);
+
+impl ElementMatrix {
+    pub const fn new (multipliers: [[u16; Element::Length as usize]; Element::Length as usize]) -> Self {
+        Self (multipliers)
+    }
+
+    pub fn find_multiplier (&self, element: Element, affinity: Option<Element>) -> u16 {
+        affinity.map_or (MULTIPLIER_BASE, |a: Element| self.0[element as usize][a as usize])
+    }
+
+    pub fn scale (&self, value: u16, element: Element, affinities: &[Option<Element>]) -> u16 {
+        let value: u32 = affinities.iter ().fold (value as u32, |v: u32, a: &Option<Element>|
+            v * (self.find_multiplier (element, *a) as u32) / (MULTIPLIER_BASE as u32)
+        );
+
+        u32::min (value, u16::MAX as u32) as u16
+    }
+}
+
+impl Default for ElementMatrix {
+    fn default () -> Self {
+        Self::new ([[MULTIPLIER_BASE; Element::Length as usize]; Element::Length as usize])
+    }
+}
+
+/*
+ * Elements scale a magic's damage and, if its appliable is a modifier, that modifier's adjustments
+ * Any other appliable (such as an attribute) is applied unscaled
+ */
+#[derive (Debug)]
+#[derive (Clone, Copy)]
+#[derive (Deserialize, Serialize)]
 pub struct Magic {
     id: ID,
     appliable: AppliableKind,
@@ -23,13 +64,16 @@ pub struct Magic {
     range: u8,
     cost: u16,
     element: Element,
+    #[serde (default)]
+    damage: u16, // HLT damage dealt to occupants
 }
 
 impl Magic {
-    pub const fn new (id: ID, appliable: AppliableKind, target: Target, area: Area, range: u8, cost: u16, element: Element) -> Self {
+    #[allow (clippy::too_many_arguments)]
+    pub const fn new (id: ID, appliable: AppliableKind, target: Target, area: Area, range: u8, cost: u16, element: Element, damage: u16) -> Self {
         assert! (matches! (target, Target::This | Target::Map));
 
-        Self { id, appliable, target, area, range, cost, element }
+        Self { id, appliable, target, area, range, cost, element, damage }
     }
 
     pub fn get_id (&self) -> ID {
@@ -47,6 +91,10 @@ impl Magic {
     pub fn get_element (&self) -> Element {
         self.element
     }
+
+    pub fn get_damage (&self) -> u16 {
+        self.damage
+    }
 }
 
 impl Tool for Magic {
@@ -68,3 +116,24 @@ impl Applier for Magic {
         self.target
     }
 }
+
+#[cfg (test)]
+mod tests {
+    use super::*;
+    use crate::tests::generate_scene;
+
+    #[test]
+    fn element_matrix_scale () {
+        let scene = generate_scene ();
+        let element_matrix = scene.get_element_matrix ();
+
+        // Test no affinity
+        assert_eq! (element_matrix.scale (20, Element::Dark, &[]), 20);
+        assert_eq! (element_matrix.scale (20, Element::Dark, &[None, None]), 20);
+        // Test resistance
+        assert_eq! (element_matrix.scale (20, Element::Dark, &[Some (Element::Dark)]), 10);
+        // Test weakness
+        assert_eq! (element_matrix.scale (20, Element::Dark, &[Some (Element::Light)]), 30);
+        assert_eq! (element_matrix.scale (20, Element::Dark, &[Some (Element::Light), Some (Element::Light)]), 45);
+    }
+}
@@ -1,10 +1,14 @@
 use crate::dynamic::Applier;
 use crate::map::Area;
 
+mod class;
+pub use self::class::*;
 mod faction;
 pub use self::faction::*;
 mod magic;
 pub use self::magic::*;
+mod retaliation;
+pub use self::retaliation::*;
 mod skill;
 pub use self::skill::*;
 mod unit;
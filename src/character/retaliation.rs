@@ -0,0 +1,52 @@
+use super::MULTIPLIER_BASE;
+use serde::{Deserialize, Serialize};
+
+const MULTIPLIER_RETALIATION: u16 = MULTIPLIER_BASE / 2; // 50%
+
+#[derive (Debug)]
+#[derive (Clone, Copy)]
+#[derive (PartialEq)]
+#[derive (Deserialize, Serialize)]
+pub enum RetaliationOrder {
+    Never,
+    Before, // defenders strike first
+    After, // defenders strike back if they survive
+}
+
+/*
+ * Defenders counterattack with their active weapon if the attacker is in range and they aren't routed
+ * Counterattacks deal a percentage of their usual damage and don't drain supplies
+ * The multiplier is out of MULTIPLIER_BASE, so 50 halves the damage
+ */
+#[derive (Debug)]
+#[derive (Clone, Copy)]
+#[derive (PartialEq)]
+#[derive (Deserialize, Serialize)]
+pub struct Retaliation {
+    order: RetaliationOrder,
+    multiplier: u16, // percentage of damage, out of MULTIPLIER_BASE
+}
+
+impl Retaliation {
+    pub const fn new (order: RetaliationOrder, multiplier: u16) -> Self {
+        Self { order, multiplier }
+    }
+
+    pub fn scale_damage (&self, damage: u16) -> u16 {
+        ((damage as u32) * (self.multiplier as u32) / (MULTIPLIER_BASE as u32)) as u16
+    }
+
+    pub fn get_order (&self) -> RetaliationOrder {
+        self.order
+    }
+
+    pub fn get_multiplier (&self) -> u16 {
+        self.multiplier
+    }
+}
+
+impl Default for Retaliation {
+    fn default () -> Self {
+        Self::new (RetaliationOrder::After, MULTIPLIER_RETALIATION)
+    }
+}
@@ -2,10 +2,12 @@ use super::Tool;
 use crate::common::{DURATION_PERMANENT, ID, Scene, Target, Timed};
 use crate::dynamic::{Appliable, AppliableKind, Applier};
 use crate::map::Area;
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
 #[derive (Debug)]
 #[derive (Clone, Copy)]
+#[derive (Deserialize, Serialize)]
 pub enum SkillKind {
     Timed (u16, u16), // current, maximum
     Passive,
@@ -13,10 +15,11 @@ pub enum SkillKind {
 }
 
 #[derive (Debug)]
-#[derive (Clone, Copy)]
+#[derive (Clone)]
+#[derive (Deserialize, Serialize)]
 pub struct Skill {
     id: ID,
-    appliables: &'static [AppliableKind],
+    appliables: Vec<AppliableKind>,
     target: Target,
     area: Area,
     range: u8,
@@ -24,10 +27,12 @@ pub struct Skill {
 }
 
 impl Skill {
-    pub const fn new (id: ID, appliables: &'static [AppliableKind], target: Target, area: Area, range: u8, kind: SkillKind) -> Self {
+    pub fn new (id: ID, appliables: &[AppliableKind], target: Target, area: Area, range: u8, kind: SkillKind) -> Self {
         assert! (!appliables.is_empty ());
         assert! (matches! (target, Target::This | Target::Ally | Target::Allies));
 
+        let appliables: Vec<AppliableKind> = appliables.to_vec ();
+
         Self { id, appliables, target, area, range, kind }
     }
 
@@ -71,6 +76,10 @@ impl Skill {
         matches! (self.kind, SkillKind::Toggled ( .. ))
     }
 
+    pub fn get_appliables (&self) -> &[AppliableKind] {
+        &self.appliables
+    }
+
     pub fn get_appliable (&self) -> AppliableKind {
         if let SkillKind::Toggled (appliable_idx) = self.kind {
             self.appliables[appliable_idx]
@@ -139,8 +148,8 @@ mod tests {
 
     fn generate_skills () -> (Skill, Skill) {
         let scene = generate_scene ();
-        let skill_0 = *scene.get_skill (&0);
-        let skill_1 = *scene.get_skill (&1);
+        let skill_0 = scene.get_skill (&0).clone ();
+        let skill_1 = scene.get_skill (&1).clone ();
 
         (skill_0, skill_1)
     }
@@ -148,7 +157,7 @@ mod tests {
     #[test]
     fn skill_switch_attribute () {
         let scene = generate_scene ();
-        let mut skill_2 = *scene.get_skill (&2);
+        let mut skill_2 = scene.get_skill (&2).clone ();
 
         assert_eq! (skill_2.switch_attribute (), (AppliableKind::Modifier (3), AppliableKind::Modifier (5)));
         assert_eq! (skill_2.get_appliable (), AppliableKind::Modifier (5));
@@ -1,13 +1,15 @@
-use super::{Element, Magic, Skill, Weapon, WeaponStatistic};
+use super::{Class, Element, Magic, MULTIPLIER_BASE, Skill, Weapon, WeaponStatistic};
 use self::UnitStatistic::{MRL, HLT, SPL, ATK, DEF, MAG, MOV, ORG};
-use crate::common::{Capacity, FACTOR_MAGIC, FACTOR_SKILL, FACTOR_WAIT, ID, Scene, Target, Timed};
+use crate::common::{Capacity, FACTOR_MAGIC, FACTOR_SKILL, FACTOR_WAIT, ID, ID_UNINITIALISED, Scene, Target, Timed};
 use crate::dynamic::{Appliable, AppliableKind, Applier, Attribute, Dynamic, Effect, Modifier, StatisticKind, Trigger};
+use crate::map::{Direction, Positioning, Terrain};
+use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display, Formatter};
 use std::rc::Rc;
 
+// Statistics are in tenths of a percent, finer than MULTIPLIER_BASE
 const PERCENT_1: u16 = 1_0;
-#[allow (clippy::inconsistent_digit_grouping)]
-const PERCENT_100: u16 = 100_0;
+const PERCENT_100: u16 = MULTIPLIER_BASE * PERCENT_1;
 const MRL_MAX: u16 = PERCENT_100; // 100.0%
 const HLT_MAX: u16 = 1000; // 1000
 const SPL_MAX: u16 = PERCENT_100; // 100.0%
@@ -28,6 +30,10 @@ const FACTOR_FIGHT: u16 = 1;
 const FACTOR_RETREAT: u16 = 2;
 const FACTOR_ROUT: u16 = 4;
 const THRESHOLD_SKILL_PASSIVE: usize = 1; // TODO: needs to be balanced
+const SIGHT_BASE: u8 = 2;
+const DIVISOR_SIGHT: u16 = 5; // MOV per additional tile of sight
+const EXPERIENCE_LEVEL: u16 = 100; // experience per level
+const LEVEL_MAX: u8 = 20;
 const UNIT_STATISTICS: [UnitStatistic; UnitStatistic::Length as usize] = [
     MRL,
     HLT,
@@ -38,9 +44,24 @@ const UNIT_STATISTICS: [UnitStatistic; UnitStatistic::Length as usize] = [
     MOV,
     ORG,
 ];
+// Only Capacity::Constant statistics grow
+const UNIT_STATISTICS_GROWTH: [UnitStatistic; 5] = [
+    ATK,
+    DEF,
+    MAG,
+    MOV,
+    ORG,
+];
+
+fn find_magic_ids (scene: &Scene, magics_usable: &[bool; Element::Length as usize], mag: u16) -> Vec<ID> {
+    scene.magics_iter ().filter (|magic: &&Magic|
+        magics_usable[magic.get_element () as usize] && mag >= magic.get_cost ()
+    ).map (|magic: &Magic| magic.get_id ()).collect ()
+}
 
 #[derive (Debug)]
 #[derive (Clone, Copy)]
+#[derive (Deserialize, Serialize)]
 pub enum UnitStatistic {
     MRL, // morale - willingness to fight (permillage)
     HLT, // manpower - number of soldiers
@@ -55,6 +76,7 @@ pub enum UnitStatistic {
 
 #[derive (Debug)]
 #[derive (Clone, Copy)]
+#[derive (Deserialize, Serialize)]
 pub struct UnitStatistics ([Capacity; UnitStatistic::Length as usize]);
 
 impl UnitStatistics {
@@ -82,6 +104,27 @@ impl UnitStatistics {
         Self (statistics)
     }
 
+    pub fn is_valid (&self) -> bool {
+        UNIT_STATISTICS.iter ().all (|statistic: &UnitStatistic| {
+            let maximum: u16 = match statistic {
+                MRL => MRL_MAX,
+                HLT => HLT_MAX,
+                SPL => SPL_MAX,
+                ATK => ATK_MAX,
+                DEF => DEF_MAX,
+                MAG => MAG_MAX,
+                MOV => MOV_MAX,
+                ORG => ORG_MAX,
+                _ => panic! ("Statistic not found"),
+            };
+
+            self.validate_statistic (*statistic) && match self.0[*statistic as usize] {
+                Capacity::Constant (c, m, b) => c <= m && b <= m && m == maximum,
+                Capacity::Quantity (c, m) => c <= m && m == maximum,
+            }
+        })
+    }
+
     fn validate_statistic (&self, statistic: UnitStatistic) -> bool {
         match statistic {
             MRL => matches! (self.0[MRL as usize], Capacity::Quantity ( .. )),
@@ -138,6 +181,20 @@ impl UnitStatistics {
         self.set_statistic (statistic, value);
     }
 
+    // Raises both base and current by the same amount, so modifiers are kept
+    fn grow_statistic (&mut self, statistic: UnitStatistic, growth: u16) {
+        assert! (self.validate_statistic (statistic));
+
+        if let Capacity::Constant (c, m, b) = self.0[statistic as usize] {
+            let base: u16 = u16::min (b.saturating_add (growth), m);
+            let current: u16 = u16::min (c.saturating_add (base - b), m);
+
+            self.0[statistic as usize] = Capacity::Constant (current, m, base);
+        } else {
+            panic! ("Invalid statistic {:?}", statistic)
+        }
+    }
+
     fn change_statistic_percentage (&mut self, statistic: UnitStatistic, change: u16, is_add: bool) {
         assert! (self.validate_statistic (statistic));
 
@@ -145,13 +202,14 @@ impl UnitStatistics {
             Capacity::Constant (_, _, b) => b,
             Capacity::Quantity (_, m) => m,
         } as f32;
-        let change: f32 = (change as f32) / 100.0;
+        let change: f32 = (change as f32) / (MULTIPLIER_BASE as f32);
         let change: u16 = (base * change) as u16;
 
         self.change_statistic_flat (statistic, change, is_add);
     }
 
-    pub fn calculate_damage (attacker: &Self, defender: &Self, weapon: &Weapon) -> (u16, u16, u16) {
+    // Positioning only scales physical damage, as magic ignores terrain and formation
+    pub fn calculate_damage (attacker: &Self, defender: &Self, weapon: &Weapon, positioning: &Positioning, terrain_defender: &Terrain) -> (u16, u16, u16) {
         let dmg_weapon: u16 = weapon.get_statistic (WeaponStatistic::DMG);
         let slh_weapon: u16 = weapon.get_statistic (WeaponStatistic::SLH);
         let prc_weapon: u16 = weapon.get_statistic (WeaponStatistic::PRC);
@@ -165,7 +223,7 @@ impl UnitStatistics {
 
         let mrl_defender: (u16, u16) = defender.get_statistic (MRL);
         let spl_defender: (u16, u16) = defender.get_statistic (SPL);
-        let def_defender: u16 = defender.get_statistic (DEF).0;
+        let def_defender: u16 = terrain_defender.scale_defence (defender.get_statistic (DEF).0);
         let mag_defender: u16 = defender.get_statistic (MAG).0;
         let org_defender: u16 = defender.get_statistic (ORG).0;
 
@@ -179,7 +237,7 @@ impl UnitStatistics {
             let add: u16 = (dcy_weapon * 2) + 1;
             let damage: u16 = u16::max ((mag_attacker + add).saturating_sub (mag_defender), 1);
 
-            damage * add
+            terrain_defender.scale_magic (damage * add)
         };
         let factor: f32 = {
             let factor_mrl: f32 = 1.0 - (mrl_defender.0 as f32) / (mrl_defender.1 as f32);
@@ -197,6 +255,7 @@ impl UnitStatistics {
             ((def_defender as f32) * factor) as u16
         };
         let damage_base: u16 = ((damage_weapon * factor) as u16).saturating_sub (minus);
+        let damage_base: u16 = ((damage_base as f32) * positioning.calculate_factor ()) as u16;
         let damage_mrl: u16 = (damage_base * (prc_weapon + 1)) + (damage_magic * (dcy_weapon + 1));
         let damage_hlt: u16 = (damage_base * (slh_weapon + 1)) + damage_magic;
         let damage_spl: u16 = damage_base + damage_magic;
@@ -241,14 +300,27 @@ impl Display for UnitStatistics {
     }
 }
 
+const fn default_class_id () -> ID {
+    ID_UNINITIALISED
+}
+
 #[derive (Debug)]
+#[derive (Clone)]
+#[derive (Deserialize, Serialize)]
 pub struct Unit {
     id: ID,
+    #[serde (skip, default = "Scene::placeholder")]
     scene: Rc<Scene>,
+    #[serde (default = "default_class_id")]
+    class_id: ID, // restored from the scene for saves that predate classes
     statistics: UnitStatistics,
     modifier_terrain_id: Option<ID>,
+    #[serde (default)]
+    modifier_weather_id: Option<ID>,
     modifiers: Vec<Modifier>,
     attribute_on_hit: Option<Attribute>,
+    #[serde (default)]
+    attribute_on_death: Option<Attribute>,
     weapons: Vec<Weapon>,
     skill_passive_id: Option<ID>,
     skills: Vec<Skill>,
@@ -256,24 +328,40 @@ pub struct Unit {
     weapon_active: usize,
     faction_id: ID,
     leader_id: Option<ID>,
+    #[serde (default)]
+    terrain_id: Option<ID>,
+    #[serde (default)]
+    facing: Option<Direction>, // direction of the last movement
+    #[serde (default)]
+    growths: [u16; UNIT_STATISTICS_GROWTH.len ()],
+    #[serde (default)]
+    level: u8,
+    #[serde (default)]
+    experience: u16,
     is_alive: bool,
 }
 
 impl Unit {
     #[allow (clippy::too_many_arguments)]
-    pub fn new (id: ID, scene: Rc<Scene>, statistics: UnitStatistics, weapons: &[ID], skill_passive_id: Option<ID>, skill_ids: &[ID], magics_usable: &[bool; Element::Length as usize], faction_id: ID, leader_id: Option<ID>) -> Self {
+    pub fn new (id: ID, scene: Rc<Scene>, class_id: ID, weapons: &[ID], skill_passive_id: Option<ID>, skill_ids: &[ID], faction_id: ID, leader_id: Option<ID>, growths: [u16; UNIT_STATISTICS_GROWTH.len ()]) -> Self {
+        let class: &Class = scene.get_class (&class_id);
+        let statistics: UnitStatistics = *class.get_statistics ();
         let modifier_terrain_id: Option<ID> = None;
+        let modifier_weather_id: Option<ID> = None;
         let modifiers: Vec<Modifier> = Vec::new ();
         let attribute_on_hit: Option<Attribute> = None;
+        let attribute_on_death: Option<Attribute> = None;
         let weapons: Vec<Weapon> = weapons.iter ().map (|w: &ID| *scene.get_weapon (w)).collect ();
-        let skills: Vec<Skill> = skill_ids.iter ().map (|s: &ID| *scene.get_skill (s)).collect ();
-        let magic_ids: Vec<ID> = scene.magics_iter ().filter (|magic: &&Magic|
-            magics_usable[magic.get_element () as usize] && statistics.get_statistic (MAG).0 >= magic.get_cost ()
-        ).map (|magic: &Magic| magic.get_id ()).collect ();
+        let skills: Vec<Skill> = skill_ids.iter ().map (|s: &ID| scene.get_skill (s).clone ()).collect ();
+        let magic_ids: Vec<ID> = find_magic_ids (&scene, class.get_magics_usable (), statistics.get_statistic (MAG).0);
         let weapon_active: usize = 0;
+        let terrain_id: Option<ID> = None;
+        let facing: Option<Direction> = None;
+        let level: u8 = 0;
+        let experience: u16 = 0;
         let is_alive: bool = true;
 
-        Self { id, scene, statistics, modifier_terrain_id, modifiers, attribute_on_hit, magic_ids, skill_passive_id, skills, weapons, weapon_active, faction_id, leader_id, is_alive }
+        Self { id, scene, class_id, statistics, modifier_terrain_id, modifier_weather_id, modifiers, attribute_on_hit, attribute_on_death, magic_ids, skill_passive_id, skills, weapons, weapon_active, faction_id, leader_id, terrain_id, facing, growths, level, experience, is_alive }
     }
 
     pub fn get_statistic (&self, statistic: UnitStatistic) -> (u16, u16) {
@@ -329,7 +417,7 @@ impl Unit {
         }
 
         if let Some (modifier_terrain_id) = modifier_terrain_id {
-            let modifier: Modifier = *self.scene.get_modifier (&modifier_terrain_id);
+            let modifier: Modifier = self.scene.get_modifier (&modifier_terrain_id).clone ();
             let appliable: Box<dyn Appliable> = Box::new (modifier);
 
             self.modifier_terrain_id = Some (modifier_terrain_id);
@@ -337,10 +425,124 @@ impl Unit {
         }
     }
 
+    pub fn change_modifier_weather (&mut self, modifier_weather_id: Option<ID>) {
+        // Reapplying the same weather would drain the unit twice
+        if self.modifier_weather_id == modifier_weather_id {
+            return
+        }
+
+        if let Some (modifier_weather_id) = self.modifier_weather_id {
+            self.remove_appliable (AppliableKind::Modifier (modifier_weather_id));
+        }
+
+        if let Some (modifier_weather_id) = modifier_weather_id {
+            let modifier: Modifier = self.scene.get_modifier (&modifier_weather_id).clone ();
+            let appliable: Box<dyn Appliable> = Box::new (modifier);
+
+            self.add_appliable (appliable);
+        }
+
+        self.modifier_weather_id = modifier_weather_id;
+    }
+
     pub fn set_leader_id (&mut self, leader_id: ID) {
         self.leader_id = self.leader_id.map (|_| leader_id);
     }
 
+    pub fn set_terrain_id (&mut self, terrain_id: ID) {
+        self.terrain_id = Some (terrain_id);
+    }
+
+    pub fn set_facing (&mut self, facing: Direction) {
+        self.facing = Some (facing);
+    }
+
+    pub fn get_scene (&self) -> &Rc<Scene> {
+        &self.scene
+    }
+
+    pub fn set_scene (&mut self, scene: Rc<Scene>) {
+        if self.class_id == ID_UNINITIALISED {
+            self.class_id = scene.get_unit_builder (&self.id).get_class_id ();
+        }
+
+        self.scene = scene;
+    }
+
+    pub fn promote (&mut self, skill_passive_id: Option<ID>) {
+        self.leader_id = None;
+        self.skill_passive_id = skill_passive_id;
+
+        if let Some (s) = skill_passive_id {
+            let skill_passive: &Skill = self.scene.get_skill (&s);
+            let appliable_passive: Box<dyn Appliable> = skill_passive.try_yield_appliable (Rc::clone (&self.scene))
+                    .unwrap_or_else (|| panic! ("Invalid skill {:?}", skill_passive));
+
+            self.add_appliable (appliable_passive);
+        }
+    }
+
+    pub fn lose_morale (&mut self, loss: u16) {
+        self.change_statistic_flat (MRL, loss, false);
+    }
+
+    // Statistics grow by however much the new class's base exceeds the old class's
+    pub fn change_class (&mut self, class_id: ID) {
+        let scene: Rc<Scene> = Rc::clone (&self.scene);
+        let class_old: &Class = scene.get_class (&self.class_id);
+        let class_new: &Class = scene.get_class (&class_id);
+
+        for statistic in UNIT_STATISTICS_GROWTH {
+            let base_old: u16 = class_old.get_statistics ().get_statistic (statistic).1;
+            let base_new: u16 = class_new.get_statistics ().get_statistic (statistic).1;
+
+            self.statistics.grow_statistic (statistic, base_new.saturating_sub (base_old));
+        }
+
+        self.weapons.retain (|w: &Weapon| class_new.is_allowed (&w.get_id ()));
+
+        if self.weapons.is_empty () {
+            self.weapons = class_new.get_weapon_ids ().iter ().map (|w: &ID| *scene.get_weapon (w)).collect ();
+        }
+
+        self.weapon_active = 0;
+        self.magic_ids = find_magic_ids (&scene, class_new.get_magics_usable (), self.get_statistic (MAG).0);
+        self.class_id = class_id;
+    }
+
+    pub fn find_promotions (&self) -> Vec<ID> {
+        let weapon_id: ID = self.get_weapon ().get_id ();
+
+        self.scene.get_class (&self.class_id).find_promotions (self.level, &[weapon_id])
+    }
+
+    // Returns the number of levels gained
+    pub fn gain_experience (&mut self, experience: u16) -> u8 {
+        let level: u8 = self.level;
+
+        self.experience = self.experience.saturating_add (experience);
+
+        while self.level < LEVEL_MAX && self.experience >= EXPERIENCE_LEVEL {
+            self.experience -= EXPERIENCE_LEVEL;
+            self.level += 1;
+
+            for (statistic, growth) in UNIT_STATISTICS_GROWTH.iter ().zip (self.growths) {
+                self.statistics.grow_statistic (*statistic, growth);
+            }
+        }
+
+        if self.level >= LEVEL_MAX {
+            self.experience = 0;
+        }
+
+        // Growing MAG can unlock magics
+        if self.level > level {
+            self.magic_ids = find_magic_ids (&self.scene, self.scene.get_class (&self.class_id).get_magics_usable (), self.get_statistic (MAG).0);
+        }
+
+        self.level - level
+    }
+
     pub fn try_add_passive (&mut self, skill_id: &ID, distance: usize) -> bool {
         if self.leader_id.is_some () {
             let appliable: AppliableKind = self.scene.get_skill (skill_id).get_appliable ();
@@ -390,7 +592,7 @@ impl Unit {
 
         for modifier in &self.modifiers {
             if modifier.is_every_turn () {
-                let modifier: Modifier = *modifier;
+                let modifier: Modifier = modifier.clone ();
                 let mut modifier: Box<Modifier> = Box::new (modifier);
 
                 modifier.set_is_every_turn (false);
@@ -507,8 +709,15 @@ impl Unit {
     }
 
     pub fn end_turn (&mut self, city_ids: &[ID], appliable: Option<Box<dyn Appliable>>) {
+        let (recover_mrl, drain_spl): (u16, u16) = self.terrain_id.map_or ((0, 0), |t: ID| {
+            let terrain: &Terrain = self.scene.get_terrain (&t);
+
+            (terrain.get_recover_mrl (), terrain.get_drain_spl ())
+        });
+
         self.recover_supplies (city_ids);
-        self.change_statistic_flat (MRL, RECOVER_MRL, true);
+        self.change_statistic_flat (MRL, RECOVER_MRL + recover_mrl, true);
+        self.change_statistic_flat (SPL, drain_spl, false);
         self.decrement_durations ();
 
         if let Some (a) = appliable {
@@ -530,6 +739,10 @@ impl Unit {
         &self.weapons[self.weapon_active]
     }
 
+    pub fn get_weapon_ids (&self) -> Vec<ID> {
+        self.weapons.iter ().map (|w: &Weapon| w.get_id ()).collect ()
+    }
+
     pub fn get_faction_id (&self) -> ID {
         self.faction_id
     }
@@ -565,6 +778,41 @@ impl Unit {
     pub fn get_leader_id (&self) -> ID {
         self.leader_id.map_or (self.id, |leader_id: ID| leader_id)
     }
+
+    pub fn get_terrain_id (&self) -> Option<ID> {
+        self.terrain_id
+    }
+
+    pub fn get_facing (&self) -> Option<Direction> {
+        self.facing
+    }
+
+    pub fn get_attribute_on_death (&self) -> Option<Attribute> {
+        self.attribute_on_death
+    }
+
+    pub fn get_class_id (&self) -> ID {
+        self.class_id
+    }
+
+    pub fn get_affinity (&self) -> Option<Element> {
+        self.scene.get_class (&self.class_id).get_affinity ()
+    }
+
+    pub fn get_level (&self) -> u8 {
+        self.level
+    }
+
+    pub fn get_experience (&self) -> u16 {
+        self.experience
+    }
+
+    pub fn get_sight (&self) -> u8 {
+        let mov: u16 = self.get_statistic (MOV).0;
+        let bonus_sight: u8 = self.terrain_id.map_or (0, |t: ID| self.scene.get_terrain (&t).get_bonus_sight ());
+
+        SIGHT_BASE + ((mov / DIVISOR_SIGHT) as u8) + bonus_sight
+    }
 }
 
 impl Applier for Unit {
@@ -634,6 +882,11 @@ impl Dynamic for Unit {
                         false
                     }
                     Trigger::OnOccupy => panic! ("Invalid trigger {:?}", trigger),
+                    Trigger::OnDeath => {
+                        self.attribute_on_death = Some (attribute);
+
+                        true
+                    }
                 }
             }
         }
@@ -664,14 +917,14 @@ impl Dynamic for Unit {
             }
             AppliableKind::Effect ( .. ) => unimplemented! (),
             AppliableKind::Attribute (attribute_id) => {
-                if let Some (attribute) = self.attribute_on_hit {
-                    if attribute.get_id () == attribute_id {
-                        self.attribute_on_hit = None;
+                if self.attribute_on_hit.is_some_and (|a: Attribute| a.get_id () == attribute_id) {
+                    self.attribute_on_hit = None;
 
-                        true
-                    } else {
-                        false
-                    }
+                    true
+                } else if self.attribute_on_death.is_some_and (|a: Attribute| a.get_id () == attribute_id) {
+                    self.attribute_on_death = None;
+
+                    true
                 } else {
                     false
                 }
@@ -683,11 +936,11 @@ impl Dynamic for Unit {
         let mut modifiers_survived: Vec<Modifier> = Vec::new ();
         let mut modifiers_expired: Vec<Modifier> = Vec::new ();
 
-        for modifier in self.modifiers.iter_mut () {
+        for mut modifier in self.modifiers.drain ( .. ) {
             if modifier.decrement_duration () {
-                modifiers_survived.push (*modifier);
+                modifiers_survived.push (modifier);
             } else {
-                modifiers_expired.push (*modifier);
+                modifiers_expired.push (modifier);
             }
         }
 
@@ -695,7 +948,7 @@ impl Dynamic for Unit {
 
         for modifier in modifiers_expired {
             if let Some (modifier_id_next) = modifier.get_next_id () {
-                let modifier: Modifier =  *self.scene.get_modifier (&modifier_id_next);
+                let modifier: Modifier = self.scene.get_modifier (&modifier_id_next).clone ();
                 let modifier: Box<Modifier> = Box::new (modifier);
 
                 self.add_appliable (modifier);
@@ -710,6 +963,14 @@ impl Dynamic for Unit {
             };
         }
 
+        if let Some (mut attribute) = self.attribute_on_death {
+            self.attribute_on_death = if attribute.decrement_duration () {
+                Some (attribute)
+            } else {
+                None
+            };
+        }
+
         for skill in self.skills.iter_mut () {
             skill.decrement_duration ();
         }
@@ -722,39 +983,75 @@ impl Dynamic for Unit {
 
 impl Display for Unit {
     fn fmt (&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write! (f, "{}: {}\n{:?}\n{:?}", self.id, self.statistics, self.modifiers, self.attribute_on_hit)
+        write! (f, "{} (level {}, {}/{} experience): {}\n{:?}\n{:?}", self.id, self.level, self.experience, EXPERIENCE_LEVEL, self.statistics, self.modifiers, self.attribute_on_hit)
     }
 }
 
 #[derive (Debug)]
+#[derive (Clone)]
+#[derive (Deserialize, Serialize)]
 pub struct UnitBuilder {
     id: ID,
-    statistics: UnitStatistics,
-    weapon_ids: &'static [ID],
+    class_id: ID,
+    weapon_ids: Vec<ID>,
     skill_passive_id: Option<ID>,
-    skill_ids: &'static [ID],
-    magics_usable: [bool; Element::Length as usize],
+    skill_ids: Vec<ID>,
     faction_id: ID,
     leader_id: Option<ID>,
+    #[serde (default)]
+    heir_id: Option<ID>,
+    #[serde (default)]
+    growths: [u16; UNIT_STATISTICS_GROWTH.len ()], // per level, in UNIT_STATISTICS_GROWTH order
 }
 
 impl UnitBuilder {
     #[allow (clippy::too_many_arguments)]
-    pub const fn new (id: ID, statistics: UnitStatistics, weapon_ids: &'static [ID], skill_passive_id: Option<ID>, skill_ids: &'static [ID], magics_usable: [bool; Element::Length as usize], faction_id: ID, leader_id: Option<ID>) -> Self {
-        Self { id, statistics, weapon_ids, skill_passive_id, skill_ids, magics_usable, faction_id, leader_id }
+    pub fn new (id: ID, class_id: ID, weapon_ids: &[ID], skill_passive_id: Option<ID>, skill_ids: &[ID], faction_id: ID, leader_id: Option<ID>, heir_id: Option<ID>, growths: [u16; UNIT_STATISTICS_GROWTH.len ()]) -> Self {
+        let weapon_ids: Vec<ID> = weapon_ids.to_vec ();
+        let skill_ids: Vec<ID> = skill_ids.to_vec ();
+
+        Self { id, class_id, weapon_ids, skill_passive_id, skill_ids, faction_id, leader_id, heir_id, growths }
     }
 
     pub fn build (&self, scene: Rc<Scene>) -> Unit {
-        Unit::new (self.id, scene, self.statistics, self.weapon_ids, self.skill_passive_id, self.skill_ids, &self.magics_usable, self.faction_id, self.leader_id)
+        Unit::new (self.id, scene, self.class_id, &self.weapon_ids, self.skill_passive_id, &self.skill_ids, self.faction_id, self.leader_id, self.growths)
     }
 
     pub fn get_id (&self) -> ID {
         self.id
     }
 
+    pub fn get_class_id (&self) -> ID {
+        self.class_id
+    }
+
+    pub fn get_weapon_ids (&self) -> &[ID] {
+        &self.weapon_ids
+    }
+
+    pub fn get_skill_passive_id (&self) -> Option<ID> {
+        self.skill_passive_id
+    }
+
+    pub fn get_skill_ids (&self) -> &[ID] {
+        &self.skill_ids
+    }
+
     pub fn get_faction_id (&self) -> ID {
         self.faction_id
     }
+
+    pub fn get_leader_id (&self) -> Option<ID> {
+        self.leader_id
+    }
+
+    pub fn get_heir_id (&self) -> Option<ID> {
+        self.heir_id
+    }
+
+    pub fn get_growths (&self) -> &[u16] {
+        &self.growths
+    }
 }
 
 #[cfg (test)]
@@ -776,9 +1073,9 @@ mod tests {
 
     fn generate_modifiers () -> (Box<Modifier>, Box<Modifier>) {
         let scene = generate_scene ();
-        let modifier_3 = *scene.get_modifier (&3);
+        let modifier_3 = scene.get_modifier (&3).clone ();
         let modifier_3 = Box::new (modifier_3);
-        let modifier_4 = *scene.get_modifier (&4);
+        let modifier_4 = scene.get_modifier (&4).clone ();
         let modifier_4 = Box::new (modifier_4);
 
         (modifier_3, modifier_4)
@@ -786,9 +1083,9 @@ mod tests {
 
     fn generate_effects () -> (Box<Effect>, Box<Effect>) {
         let scene = generate_scene ();
-        let effect_0 = *scene.get_effect (&0);
+        let effect_0 = scene.get_effect (&0).clone ();
         let effect_0 = Box::new (effect_0);
-        let effect_1 = *scene.get_effect (&1);
+        let effect_1 = scene.get_effect (&1).clone ();
         let effect_1 = Box::new (effect_1);
 
         (effect_0, effect_1)
@@ -876,6 +1173,24 @@ mod tests {
         assert_eq! (unit_0.modifiers.len (), 1);
     }
 
+    #[test]
+    fn unit_change_modifier_weather () {
+        let (mut unit_0, _, _) = generate_units ();
+        let mov = unit_0.get_statistic (MOV).0;
+
+        unit_0.change_modifier_weather (Some (11));
+        assert_eq! (unit_0.modifier_weather_id.unwrap (), 11);
+        assert_eq! (unit_0.get_statistic (MOV).0, mov * 9 / 10);
+        // Test same modifier
+        unit_0.change_modifier_weather (Some (11));
+        assert_eq! (unit_0.modifiers.len (), 1);
+        // Test removed modifier
+        unit_0.change_modifier_weather (None);
+        assert! (unit_0.modifier_weather_id.is_none ());
+        assert! (unit_0.modifiers.is_empty ());
+        assert_eq! (unit_0.get_statistic (MOV).0, mov);
+    }
+
     #[test]
     fn unit_set_leader_id () {
         let (mut unit_0, mut unit_1, _) = generate_units ();
@@ -890,6 +1205,63 @@ mod tests {
         assert_eq! (unit_1.get_leader_id (), 0);
     }
 
+    #[test]
+    fn unit_set_scene () {
+        let (unit_0, _, _) = generate_units ();
+        let unit_0 = ron::to_string (&unit_0).unwrap ().replace ("class_id:2,", "");
+        let mut unit_0: Unit = ron::from_str (&unit_0).unwrap ();
+
+        // Test save without class
+        assert! (unit_0.scene.unit_builders_iter ().next ().is_none ());
+        assert_eq! (unit_0.get_class_id (), ID_UNINITIALISED);
+        unit_0.set_scene (generate_scene ());
+        assert_eq! (unit_0.get_class_id (), 2);
+    }
+
+    #[test]
+    fn unit_change_class () {
+        let (_, mut unit_1, mut unit_2) = generate_units ();
+
+        // Test statistics and magics
+        unit_1.change_class (1);
+        assert_eq! (unit_1.get_class_id (), 1);
+        assert_eq! (unit_1.get_statistic (DEF), (20, 20));
+        assert_eq! (unit_1.get_statistic (MOV), (20, 20));
+        assert! (unit_1.get_magic_ids ().is_empty ());
+        unit_1.change_class (2);
+        assert_eq! (unit_1.get_magic_ids (), &[0, 2, 3, 4]);
+        // Test disallowed weapons
+        unit_2.change_class (0);
+        assert_eq! (unit_2.weapons.len (), 1);
+        assert_eq! (unit_2.get_weapon ().get_id (), 1);
+        unit_2.change_class (1);
+        assert_eq! (unit_2.get_weapon ().get_id (), 0);
+    }
+
+    #[test]
+    fn unit_gain_experience () {
+        let (mut unit_0, _, _) = generate_units ();
+
+        // Test no level
+        assert_eq! (unit_0.gain_experience (50), 0);
+        assert_eq! (unit_0.get_experience (), 50);
+        assert_eq! (unit_0.get_statistic (ATK), (20, 20));
+        assert! (!unit_0.get_magic_ids ().contains (&1));
+        // Test multiple levels
+        assert_eq! (unit_0.gain_experience (200), 2);
+        assert_eq! (unit_0.get_level (), 2);
+        assert_eq! (unit_0.get_experience (), 50);
+        assert_eq! (unit_0.get_statistic (ATK), (24, 24));
+        assert! (unit_0.get_magic_ids ().contains (&1));
+        assert_eq! (unit_0.get_statistic (MOV), (12, 12));
+        assert_eq! (unit_0.get_statistic (ORG), (1100, 1100));
+        // Test maximum level
+        unit_0.gain_experience (u16::MAX);
+        assert_eq! (unit_0.get_level (), LEVEL_MAX);
+        assert_eq! (unit_0.get_experience (), 0);
+        assert_eq! (unit_0.get_statistic (ORG), (ORG_MAX, ORG_MAX));
+    }
+
     #[test]
     fn unit_try_add_passive () {
         let (mut unit_0, mut unit_1, _) = generate_units ();
@@ -911,9 +1283,9 @@ mod tests {
     fn unit_start_turn () {
         let scene = generate_scene ();
         let (mut unit_0, _, _) = generate_units ();
-        let modifier_7 = *scene.get_modifier (&7);
+        let modifier_7 = scene.get_modifier (&7).clone ();
         let modifier_7 = Box::new (modifier_7);
-        let modifier_8 = *scene.get_modifier (&8);
+        let modifier_8 = scene.get_modifier (&8).clone ();
         let modifier_8 = Box::new (modifier_8);
 
         // Test normal modifier
@@ -962,7 +1334,7 @@ mod tests {
         let attribute_5 = Box::new (attribute_5);
 
         // Test normal attack
-        let (damage_mrl, damage_hlt, damage_spl) = UnitStatistics::calculate_damage (&statistics_0, &statistics_2, &weapon);
+        let (damage_mrl, damage_hlt, damage_spl) = UnitStatistics::calculate_damage (&statistics_0, &statistics_2, &weapon, &Positioning::default (), scene.get_terrain (&0));
         let mrl_2_0 = unit_2.get_statistic (MRL).0;
         let hlt_2_0 = unit_2.get_statistic (HLT).0;
         let spl_2_0 = unit_2.get_statistic (SPL).0;
@@ -977,7 +1349,7 @@ mod tests {
         unit_0.add_appliable (attribute_5);
         let weapon = *unit_2.get_weapon ();
         let (damage_mrl, damage_hlt, damage_spl) =
-            UnitStatistics::calculate_damage (&statistics_2, &statistics_0, &weapon);
+            UnitStatistics::calculate_damage (&statistics_2, &statistics_0, &weapon, &Positioning::default (), scene.get_terrain (&0));
         let mrl_0_0 = unit_0.get_statistic (MRL).0;
         let hlt_0_0 = unit_0.get_statistic (HLT).0;
         let spl_0_0 = unit_0.get_statistic (SPL).0;
@@ -994,7 +1366,7 @@ mod tests {
         assert_eq! (unit_2.switch_weapon (), 1);
         let weapon = *unit_2.get_weapon ();
         let (damage_mrl, damage_hlt, damage_spl) =
-            UnitStatistics::calculate_damage (&statistics_2, &statistics_0, &weapon);
+            UnitStatistics::calculate_damage (&statistics_2, &statistics_0, &weapon, &Positioning::default (), scene.get_terrain (&0));
         assert! (unit_0
             .take_damage (damage_mrl, damage_hlt, damage_spl)
             .is_some ());
@@ -1072,7 +1444,7 @@ mod tests {
     fn unit_end_turn () {
         let scene = generate_scene ();
         let (mut unit_0, _, _) = generate_units ();
-        let effect_0 = *scene.get_effect (&0);
+        let effect_0 = scene.get_effect (&0).clone ();
         let effect_0 = Box::new (effect_0) as Box<dyn Appliable>;
         let effect_0 = Some (effect_0);
 
@@ -1102,6 +1474,16 @@ mod tests {
         assert! (hlt_0_1 < hlt_0_2);
         assert! (spl_0_1 < spl_0_2);
         assert_eq! (unit_0.skills[0].get_duration (), 0);
+        // Test terrain recover
+        let terrain_1 = scene.get_terrain (&1);
+        let sight_0_0 = unit_0.get_sight ();
+        unit_0.set_terrain_id (1);
+        unit_0.end_turn (&[], None);
+        let mrl_0_3 = unit_0.get_statistic (MRL).0;
+        let spl_0_3 = unit_0.get_statistic (SPL).0;
+        assert_eq! (mrl_0_2 + RECOVER_MRL + terrain_1.get_recover_mrl (), mrl_0_3);
+        assert_eq! (spl_0_2 - terrain_1.get_drain_spl (), spl_0_3);
+        assert_eq! (unit_0.get_sight (), sight_0_0 + terrain_1.get_bonus_sight ());
     }
 
     #[test]
@@ -1122,7 +1504,7 @@ mod tests {
         let scene = generate_scene ();
         let (mut unit_0, mut unit_1, _) = generate_units ();
         let (modifier_3, modifier_4) = generate_modifiers ();
-        let modifier_5 = *scene.get_modifier (&5);
+        let modifier_5 = scene.get_modifier (&5).clone ();
         let modifier_5 = Box::new (modifier_5);
         let (effect_0, effect_1) = generate_effects ();
         let (_, _, attribute_5) = generate_attributes ();
@@ -1167,6 +1549,11 @@ mod tests {
         // Test weapon attribute
         assert! (unit_0.add_appliable (attribute_6));
         assert! (unit_0.weapons[unit_0.weapon_active].try_yield_appliable (Rc::clone (&scene)).is_some ());
+        // Test death attribute
+        assert! (unit_0.add_appliable (Box::new (*scene.get_attribute (&11))));
+        assert_eq! (unit_0.get_attribute_on_death ().unwrap ().get_id (), 11);
+        assert! (unit_0.remove_appliable (AppliableKind::Attribute (11)));
+        assert! (unit_0.get_attribute_on_death ().is_none ());
     }
 
     #[test]
@@ -1197,7 +1584,7 @@ mod tests {
         let scene = generate_scene ();
         let (mut unit_0, mut unit_1, _) = generate_units ();
         let (modifier_3, modifier_4) = generate_modifiers ();
-        let modifier_6 = *scene.get_modifier (&6);
+        let modifier_6 = scene.get_modifier (&6).clone ();
         let modifier_6 = Box::new (modifier_6);
         let (attribute_0, attribute_1, _) = generate_attributes ();
 
@@ -2,6 +2,7 @@ use super::Tool;
 use crate::common::{ID, Scene, Target, Timed};
 use crate::dynamic::{Appliable, AppliableKind, Applier, Attribute, Dynamic, Trigger};
 use crate::map::Area;
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
 type WeaponStatistics = [u8; WeaponStatistic::Length as usize];
@@ -18,11 +19,13 @@ pub enum WeaponStatistic {
 
 #[derive (Debug)]
 #[derive (Clone, Copy)]
+#[derive (Deserialize, Serialize)]
 pub struct Weapon {
     id: ID,
     statistics: WeaponStatistics,
     area: Area,
     range: u8,
+    #[serde (default)]
     attribute_on_attack: Option<Attribute>,
 }
 
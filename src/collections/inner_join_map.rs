@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::hash::Hash;
 
@@ -8,6 +9,9 @@ use std::hash::Hash;
  * Mappings are duplicated
  */
 #[derive (Debug)]
+#[derive (Clone)]
+#[derive (Deserialize, Serialize)]
+#[serde (bound (deserialize = "T: Deserialize<'de> + Eq + Hash, U: Deserialize<'de> + Eq + Hash"))]
 pub struct InnerJoinMap<T, U> {
     map_first: HashMap<T, U>,
     map_second: HashMap<U, T>,
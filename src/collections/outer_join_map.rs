@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
@@ -10,6 +11,9 @@ use std::fmt::Debug;
  * Mappings are duplicated
  */
 #[derive (Debug)]
+#[derive (Clone)]
+#[derive (Deserialize, Serialize)]
+#[serde (bound (deserialize = "T: Deserialize<'de> + Eq + Hash, U: Deserialize<'de> + Eq + Hash"))]
 pub struct OuterJoinMap<T, U> {
     map_first: HashMap<T, HashSet<U>>,
     map_second: HashMap<U, T>
@@ -1,149 +1,243 @@
 pub mod objects {
-    use crate::character::{SkillKind, Element, FactionBuilder, Magic, Skill, UnitBuilder, UnitStatistics, Weapon};
+    use crate::character::{SkillKind, Class, Element, ElementMatrix, FactionBuilder, Magic, Requirement, Retaliation, RetaliationOrder, Skill, Succession, UnitBuilder, UnitStatistics, Weapon};
     use crate::character::UnitStatistic::{MRL, HLT, SPL, ATK, DEF, MAG, MOV, ORG};
-    use crate::common::{DURATION_PERMANENT, ID_UNINITIALISED, Target};
+    use crate::common::{DURATION_PERMANENT, ID, ID_UNINITIALISED, Information, Objective, Target};
     use crate::dynamic::{Attribute, Effect, Modifier, Trigger};
+    use crate::event::{Condition, Consequence, Script};
     use crate::dynamic::AppliableKind::{Attribute as AppliableAttribute, Effect as AppliableEffect, Modifier as AppliableModifier};
     use crate::dynamic::StatisticKind::{Tile, Unit};
-    use crate::map::{Area, City, Location, Terrain, TileBuilder};
+    use crate::map::{Area, City, Location, Terrain, TileBuilder, Weather, WeatherKind, ZoneOfControl, ZoneRule};
 
-    pub const MODIFIERS: &[Modifier] = &[
-        Modifier::new (0, &[
-            (Tile (false), 1, true),
-        ], 2, false, false, None), // terrain_cost_+1
-        Modifier::new (1, &[
-            (Tile (false), 1, false),
-        ], DURATION_PERMANENT, false, false, None), // terrain_cost_-1
-        Modifier::new (2, &[
-            (Tile (true), 1, false),
-        ], 1, false, false, Some (0)), // terrain_cost_=1
-        Modifier::new (3, &[
-            (Unit (ATK), 20, true),
-        ], 2, true, false, None), // atk_+20
-        Modifier::new (4, &[
-            (Unit (ATK), 10, true),
-            (Unit (DEF), 10, false),
-        ], DURATION_PERMANENT, true, true, None), // atk_+10_def_-10
-        Modifier::new (5, &[
-            (Unit (ATK), 10, false),
-        ], 1, false, false, None), // atk_-10
-        Modifier::new (6, &[
-            (Unit (HLT), 2, false),
-        ], 1, false, false, Some (5)), // poison
-        Modifier::new (7, &[
-            (Unit (DEF), 10, false),
-        ], 1, false, false, None), // def_-10
-        Modifier::new (8, &[
-            (Unit (MAG), 10, false),
-        ], 1, true, true, None), // mag_-10
-        Modifier::new (9, &[
-            (Tile (true), 1, false),
-        ], 1, false, false, None), // terrain_cost_=1
-    ];
-    pub const EFFECTS: &[Effect] = &[
-        Effect::new (0, &[
-            (Unit (HLT), 2, false),
-        ], true), // hlt_-2
-        Effect::new (1, &[
-            (Unit (ATK), 5, true),
-            (Unit (DEF), 5, false),
-        ], false), // atk_+5_def_-5
-    ];
-    pub const ATTRIBUTES: &[Attribute] = &[
-        Attribute::new (0, AppliableModifier
-         (3), Trigger::OnHit, DURATION_PERMANENT), // atk_stack_up
-        Attribute::new (1, AppliableModifier
-         (5), Trigger::OnHit, 2), // atk_down
-        Attribute::new (2, AppliableModifier
-         (6), Trigger::OnOccupy, 2), // poison_2
-        Attribute::new (3, AppliableModifier
-         (1), Trigger::OnOccupy, DURATION_PERMANENT), // terrain_cost_down_permanent
-        Attribute::new (4, AppliableModifier
-         (6), Trigger::OnOccupy, 2), // poison_2
-        Attribute::new (5, AppliableModifier
-         (6), Trigger::OnHit, 2), // poison_2
-        Attribute::new (6, AppliableModifier
-         (6), Trigger::OnAttack, 2), // poison_2
-        Attribute::new (7, AppliableModifier
-         (6), Trigger::OnAttack, DURATION_PERMANENT), // poison_permanent
-        Attribute::new (8, AppliableModifier
-         (4), Trigger::OnHit, DURATION_PERMANENT), // atk_up_def_down
-        Attribute::new (9, AppliableModifier
-         (7), Trigger::OnHit, DURATION_PERMANENT), // def_down
-        Attribute::new (10, AppliableModifier
-         (8), Trigger::OnAttack, DURATION_PERMANENT), // mag_down
-    ];
-    pub const TERRAINS: &[Terrain] = &[
-        Terrain::new (None, 1), // passable_1
-        Terrain::new (Some (3), 2), // passable_2
-        Terrain::new (None, 0), // impassable
-    
-    ];
-    pub const CITIES: &[City] = &[
-        City::new (10, 1, 1, Some (1)),
-        City::new (10, 2, 1, None),
-        City::new (10, 1, 2, Some (3)),
-        City::new (10, 2, 2, None),
-    ];
-    pub const WEAPONS: &[Weapon] = &[
-        Weapon::new (0, [20, 1, 1, 0], Area::Single, 1), // single
-        Weapon::new (1, [20, 0, 2, 0], Area::Path (0), 2), // path
-        Weapon::new (2, [10, 1, 0, 1], Area::Radial (2), 2), // radial
-    ];
-    pub const MAGICS: &[Magic] = &[
-        Magic::new (0, AppliableModifier (4), Target::This, Area::Single, 0, 10, Element::Dark), // def_self
-        Magic::new (1, AppliableModifier (3), Target::This, Area::Single, 0, 21, Element::Dark), // atk_self
-        Magic::new (2, AppliableModifier (6), Target::This, Area::Single, 0, 10, Element::Matter), // poison_target_others
-        Magic::new (3, AppliableAttribute (2), Target::Map, Area::Radial (2), 0, 10, Element::Light), // poison_map
-        Magic::new (4, AppliableAttribute (2), Target::Map, Area::Path (1), 2, 10, Element::Dark), // poison_map
-    ];
-    pub const SKILLS: &[Skill] = &[
-        Skill::new (0, &[AppliableModifier (6)], Target::This, Area::Single, 0, SkillKind::Timed (0, 2)),
-        Skill::new (1, &[AppliableModifier (5)], Target::This, Area::Single, 0, SkillKind::Passive),
-        Skill::new (2, &[AppliableModifier (3), AppliableModifier (5)], Target::This, Area::Radial (2), 0, SkillKind::Toggled (0)),
-        Skill::new (3, &[AppliableModifier (0)], Target::This, Area::Radial (2), 0, SkillKind::Timed (1, 1)), // DO NOT USE
-        Skill::new (4, &[AppliableModifier (4)], Target::Ally, Area::Single, 0, SkillKind::Timed (0, 2)),
-        Skill::new (5, &[AppliableModifier (4)], Target::Allies, Area::Radial (2), 0, SkillKind::Timed (0, 2)),
-        Skill::new (6, &[AppliableModifier (4)], Target::This, Area::Single, 0, SkillKind::Timed (0, 2)),
-    ];
-    pub const FACTION_BUILDERS: &[FactionBuilder] = &[
-        FactionBuilder::new (0, &[2]),
-        FactionBuilder::new (1, &[]),
-        FactionBuilder::new (2, &[0]),
-    ];
-    pub const UNIT_BUILDERS: &[UnitBuilder] = &[
-        UnitBuilder::new (0,
-            UnitStatistics::new (1000, 1000, 1000, 20, 20, 20, 10, 1000),
-            &[0], Some (1), &[0, 2, 3], [true, true, true], 0, None
-        ),
-        UnitBuilder::new (1,
-            UnitStatistics::new (1000, 1000, 1000, 20, 20, 20, 10, 1000),
-            &[0], None, &[], [false, true, false], 0, Some (ID_UNINITIALISED)
-        ),
-        UnitBuilder::new (2,
-            UnitStatistics::new (1000, 1000, 1000, 20, 20, 20, 10, 1000),
-            &[1, 2], Some (1), &[0, 2, 3], [false, false, false], 1, None
-        ),
-        UnitBuilder::new (3,
-            UnitStatistics::new (1000, 1000, 1000, 20, 20, 20, 10, 1000),
-            &[0], Some (1), &[4, 5, 6], [false, false, false], 0, Some (ID_UNINITIALISED)
-        ),
-        UnitBuilder::new (4,
-            UnitStatistics::new (1000, 1000, 1000, 20, 20, 20, 10, 1000),
-            &[0], Some (1), &[0, 2, 3], [false, false, false], 2, None
-        ),
-    ];
-    pub const TILE_BUILDERS: &[&[TileBuilder]] = &[
-        &[TileBuilder::new (0, 0, Some (0)), TileBuilder::new (0, 1, None), TileBuilder::new (0, 0, Some (1))],
-        &[TileBuilder::new (1, 2, Some (2)), TileBuilder::new (1, 1, None), TileBuilder::new (2, 0, None)],
-    ];
-    pub const UNIT_LOCATIONS: &[Option<Location>] = &[
-        Some ((0, 0)),
-        None,
-        Some ((1, 0)),
-        None,
-        None,
-    ];    
+    pub fn modifiers () -> Vec<Modifier> {
+        vec! [
+            Modifier::new (0, &[
+                (Tile (false), 1, true),
+            ], 2, false, false, None), // terrain_cost_+1
+            Modifier::new (1, &[
+                (Tile (false), 1, false),
+            ], DURATION_PERMANENT, false, false, None), // terrain_cost_-1
+            Modifier::new (2, &[
+                (Tile (true), 1, false),
+            ], 1, false, false, Some (0)), // terrain_cost_=1
+            Modifier::new (3, &[
+                (Unit (ATK), 20, true),
+            ], 2, true, false, None), // atk_+20
+            Modifier::new (4, &[
+                (Unit (ATK), 10, true),
+                (Unit (DEF), 10, false),
+            ], DURATION_PERMANENT, true, true, None), // atk_+10_def_-10
+            Modifier::new (5, &[
+                (Unit (ATK), 10, false),
+            ], 1, false, false, None), // atk_-10
+            Modifier::new (6, &[
+                (Unit (HLT), 2, false),
+            ], 1, false, false, Some (5)), // poison
+            Modifier::new (7, &[
+                (Unit (DEF), 10, false),
+            ], 1, false, false, None), // def_-10
+            Modifier::new (8, &[
+                (Unit (MAG), 10, false),
+            ], 1, true, true, None), // mag_-10
+            Modifier::new (9, &[
+                (Tile (true), 1, false),
+            ], 1, false, false, None), // terrain_cost_=1
+            Modifier::new (10, &[
+                (Tile (false), 1, true),
+            ], DURATION_PERMANENT, false, false, None), // rain_cost_+1
+            Modifier::new (11, &[
+                (Unit (MOV), 10, false),
+            ], DURATION_PERMANENT, false, false, None), // rain_mov_-10
+            Modifier::new (12, &[
+                (Unit (SPL), 5, false),
+            ], DURATION_PERMANENT, true, true, None), // heat_spl_-5
+        ]
+    }
+    pub fn effects () -> Vec<Effect> {
+        vec! [
+            Effect::new (0, &[
+                (Unit (HLT), 2, false),
+            ], true), // hlt_-2
+            Effect::new (1, &[
+                (Unit (ATK), 5, true),
+                (Unit (DEF), 5, false),
+            ], false), // atk_+5_def_-5
+        ]
+    }
+    pub fn attributes () -> Vec<Attribute> {
+        vec! [
+            Attribute::new (0, AppliableModifier
+             (3), Trigger::OnHit, DURATION_PERMANENT), // atk_stack_up
+            Attribute::new (1, AppliableModifier
+             (5), Trigger::OnHit, 2), // atk_down
+            Attribute::new (2, AppliableModifier
+             (6), Trigger::OnOccupy, 2), // poison_2
+            Attribute::new (3, AppliableModifier
+             (1), Trigger::OnOccupy, DURATION_PERMANENT), // terrain_cost_down_permanent
+            Attribute::new (4, AppliableModifier
+             (6), Trigger::OnOccupy, 2), // poison_2
+            Attribute::new (5, AppliableModifier
+             (6), Trigger::OnHit, 2), // poison_2
+            Attribute::new (6, AppliableModifier
+             (6), Trigger::OnAttack, 2), // poison_2
+            Attribute::new (7, AppliableModifier
+             (6), Trigger::OnAttack, DURATION_PERMANENT), // poison_permanent
+            Attribute::new (8, AppliableModifier
+             (4), Trigger::OnHit, DURATION_PERMANENT), // atk_up_def_down
+            Attribute::new (9, AppliableModifier
+             (7), Trigger::OnHit, DURATION_PERMANENT), // def_down
+            Attribute::new (10, AppliableModifier
+             (8), Trigger::OnAttack, DURATION_PERMANENT), // mag_down
+            Attribute::new (11, AppliableModifier
+             (7), Trigger::OnDeath, DURATION_PERMANENT), // def_down_death
+        ]
+    }
+    pub fn terrains () -> Vec<Terrain> {
+        vec! [
+            Terrain::new (None, 1, 0, 0, 0, 0, 0, None, Information::new ("Plains", &["Open ground offering no cover"])), // passable_1
+            Terrain::new (Some (3), 2, 25, 20, 20, 10, 1, Some (Element::Dark), Information::new ("Forest", &["Woodland that shelters its occupants but strains their supplies"])), // passable_2
+            Terrain::new (None, 0, 0, 0, 0, 0, 0, None, Information::new ("Mountains", &["Impassable peaks"])), // impassable
+        
+        ]
+    }
+    pub fn cities () -> Vec<City> {
+        vec! [
+            City::new (10, 1, 1, Some (1)),
+            City::new (10, 2, 1, None),
+            City::new (10, 1, 2, Some (3)),
+            City::new (10, 2, 2, None),
+        ]
+    }
+    pub fn weapons () -> Vec<Weapon> {
+        vec! [
+            Weapon::new (0, [20, 1, 1, 0], Area::Single, 1), // single
+            Weapon::new (1, [20, 0, 2, 0], Area::Path (0), 2), // path
+            Weapon::new (2, [10, 1, 0, 1], Area::Radial (2), 2), // radial
+        ]
+    }
+    pub fn magics () -> Vec<Magic> {
+        vec! [
+            Magic::new (0, AppliableModifier (4), Target::This, Area::Single, 0, 10, Element::Dark, 0), // def_self
+            Magic::new (1, AppliableModifier (3), Target::This, Area::Single, 0, 21, Element::Dark, 0), // atk_self
+            Magic::new (2, AppliableModifier (6), Target::This, Area::Single, 0, 10, Element::Matter, 0), // poison_target_others
+            Magic::new (3, AppliableAttribute (2), Target::Map, Area::Radial (2), 0, 10, Element::Light, 20), // poison_map
+            Magic::new (4, AppliableAttribute (2), Target::Map, Area::Path (1), 2, 10, Element::Dark, 20), // poison_map
+        ]
+    }
+    pub fn skills () -> Vec<Skill> {
+        vec! [
+            Skill::new (0, &[AppliableModifier (6)], Target::This, Area::Single, 0, SkillKind::Timed (0, 2)),
+            Skill::new (1, &[AppliableModifier (5)], Target::This, Area::Single, 0, SkillKind::Passive),
+            Skill::new (2, &[AppliableModifier (3), AppliableModifier (5)], Target::This, Area::Radial (2), 0, SkillKind::Toggled (0)),
+            Skill::new (3, &[AppliableModifier (0)], Target::This, Area::Radial (2), 0, SkillKind::Timed (1, 1)), // DO NOT USE
+            Skill::new (4, &[AppliableModifier (4)], Target::Ally, Area::Single, 0, SkillKind::Timed (0, 2)),
+            Skill::new (5, &[AppliableModifier (4)], Target::Allies, Area::Radial (2), 0, SkillKind::Timed (0, 2)),
+            Skill::new (6, &[AppliableModifier (4)], Target::This, Area::Single, 0, SkillKind::Timed (0, 2)),
+        ]
+    }
+    pub fn classes () -> Vec<Class> {
+        vec! [
+            Class::new (0,
+                UnitStatistics::new (1000, 1000, 1000, 20, 20, 20, 10, 1000),
+                &[0, 1], [false, false, false], &[], &[(1, Requirement::Level (5)), (3, Requirement::Weapon (1))], None
+            ), // infantry
+            Class::new (1,
+                UnitStatistics::new (1000, 1000, 1000, 20, 15, 20, 20, 1000),
+                &[0], [false, false, false], &[(1, 200)], &[], None
+            ), // cavalry
+            Class::new (2,
+                UnitStatistics::new (1000, 1000, 1000, 20, 20, 20, 10, 1000),
+                &[0], [true, true, true], &[], &[], Some (Element::Dark)
+            ), // mage_corps
+            Class::new (3,
+                UnitStatistics::new (1000, 1000, 1000, 20, 20, 20, 10, 1000),
+                &[1, 2], [false, false, false], &[], &[], None
+            ), // artillery
+            Class::new (4,
+                UnitStatistics::new (1000, 1000, 1000, 20, 20, 20, 10, 1000),
+                &[0], [false, true, false], &[], &[(2, Requirement::Level (3))], Some (Element::Light)
+            ), // acolytes
+        ]
+    }
+    pub fn faction_builders () -> Vec<FactionBuilder> {
+        vec! [
+            FactionBuilder::new (0, &[2], Succession::Heir),
+            FactionBuilder::new (1, &[], Succession::Organisation),
+            FactionBuilder::new (2, &[0], Succession::Nearest),
+        ]
+    }
+    pub fn unit_builders () -> Vec<UnitBuilder> {
+        vec! [
+            UnitBuilder::new (0, 2, &[0], Some (1), &[0, 2, 3], 0, None, Some (3), [2, 2, 2, 1, 50]),
+            UnitBuilder::new (1, 4, &[0], None, &[], 0, Some (ID_UNINITIALISED), None, [2, 2, 2, 1, 50]),
+            UnitBuilder::new (2, 3, &[1, 2], Some (1), &[0, 2, 3], 1, None, None, [2, 2, 2, 1, 50]),
+            UnitBuilder::new (3, 0, &[0], Some (1), &[4, 5, 6], 0, Some (ID_UNINITIALISED), None, [2, 2, 2, 1, 50]),
+            UnitBuilder::new (4, 0, &[0], Some (1), &[0, 2, 3], 2, None, None, [2, 2, 2, 1, 50]),
+        ]
+    }
+    pub fn tile_builders () -> Vec<Vec<TileBuilder>> {
+        vec! [
+            vec! [TileBuilder::new (0, 0, Some (0)), TileBuilder::new (0, 1, None), TileBuilder::new (0, 0, Some (1))],
+            vec! [TileBuilder::new (1, 2, Some (2)), TileBuilder::new (1, 1, None), TileBuilder::new (2, 0, None)],
+        ]
+    }
+    pub fn unit_locations () -> Vec<Option<Location>> {
+        vec! [
+            Some ((0, 0)),
+            None,
+            Some ((1, 0)),
+            None,
+            None,
+        ]
+    }
+    pub fn objectives () -> Vec<(ID, Objective)> {
+        vec! [
+            (0, Objective::Eliminate),
+            (1, Objective::Eliminate),
+            (1, Objective::Assassinate (0)),
+        ]
+    }
+    pub fn weathers () -> Vec<Weather> {
+        vec! [
+            Weather::new (WeatherKind::Clear, None, None), // clear
+            Weather::new (WeatherKind::Rain, Some (10), Some (11)), // rain
+            Weather::new (WeatherKind::Heat, None, Some (12)), // heat
+        ]
+    }
+    pub fn weather_pattern () -> Vec<(ID, usize)> {
+        vec! [
+            (0, 12),
+            (1, 4),
+            (2, 4),
+        ]
+    }
+    pub fn scripts () -> Vec<Script> {
+        vec! [
+            Script::new (Condition::TurnReached (25), vec! [
+                Consequence::Spawn (4, (0, 1)),
+                Consequence::Dialogue (String::from ("Reinforcements arrive")),
+            ], false), // reinforcements
+            Script::new (Condition::CityCaptured (1, Some (1)), vec! [
+                Consequence::Ally (0, 2, false),
+                Consequence::Modify (0, (0, 2), 1),
+            ], false), // betrayal
+            Script::new (Condition::FactionEliminated (1), vec! [
+                Consequence::Dialogue (String::from ("The enemy is routed")),
+            ], true), // victory
+        ]
+    }
+    pub fn retaliation () -> Retaliation {
+        Retaliation::new (RetaliationOrder::After, 50)
+    }
+    pub fn element_matrix () -> ElementMatrix {
+        ElementMatrix::new ([
+            [100, 100, 100], // matter
+            [100, 50, 150], // dark
+            [100, 150, 50], // light
+        ])
+    }
+    pub fn zone_of_control () -> ZoneOfControl {
+        ZoneOfControl::new (ZoneRule::Stop, &[1], &[6])
+    }
 }
 
 pub mod textures {
@@ -1,35 +1,44 @@
+use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display, Formatter};
 
 #[derive (Debug)]
+#[derive (Clone)]
+#[derive (Deserialize, Serialize)]
 pub struct Information {
-    name: &'static str,
-    descriptions: &'static [&'static str],
+    name: String,
+    descriptions: Vec<String>,
+    #[serde (default)]
     description_current: usize,
 }
 
 impl Information {
-    pub const fn new (name: &'static str, descriptions: &'static [&'static str]) -> Self {
+    pub fn new (name: &str, descriptions: &[&str]) -> Self {
+        assert! (!descriptions.is_empty ());
+
+        let name: String = name.to_string ();
+        let descriptions: Vec<String> = descriptions.iter ().map (|d: &&str| d.to_string ()).collect ();
         let description_current: usize = 0;
 
         Self { name, descriptions, description_current }
     }
 
     pub fn get_name (&self) -> &str {
-        self.name
+        &self.name
     }
 
     pub fn get_description (&self) -> &str {
-        self.descriptions[self.description_current]
+        &self.descriptions[self.description_current]
+    }
+
+    // Deserialised information skips new (), so the scene checks this instead
+    pub fn is_valid (&self) -> bool {
+        self.description_current < self.descriptions.len ()
     }
 }
 
 impl Default for Information {
     fn default () -> Self {
-        let name: &str = "";
-        let descriptions: &[&str] = &[""];
-        let description_current: usize = 0;
-
-        Self { name, descriptions, description_current }
+        Self::new ("", &[""])
     }
 }
 
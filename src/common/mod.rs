@@ -1,8 +1,11 @@
 mod debug;
 mod information;
 pub use self::information::*;
+mod objective;
+pub use self::objective::*;
 mod scene;
 pub use self::scene::*;
+use serde::{Deserialize, Serialize};
 
 pub type ID = usize;
 
@@ -36,10 +39,11 @@ pub trait Timed {
  * Weapons only target Enemy or Enemies
  * Skills only target This, Ally, or Allies
  * Magics only target This or Map
- * Attributes only target This (None), Enemy (OnHit/OnAttack), or Map (OnOccupy)
+ * Attributes only target This (None), Enemy (OnHit/OnAttack), Allies (OnDeath), or Map (OnOccupy)
  */
 #[derive (Debug)]
 #[derive (Clone, Copy)]
+#[derive (Deserialize, Serialize)]
 pub enum Target {
     This,
     Ally,
@@ -59,6 +63,7 @@ pub enum Target {
 
 #[derive (Debug)]
 #[derive (Clone, Copy)]
+#[derive (Deserialize, Serialize)]
 pub enum Capacity {
     Constant (u16, u16, u16), // current, maximum, base
     Quantity (u16, u16), // current, maximum
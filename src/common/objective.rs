@@ -0,0 +1,18 @@
+use crate::common::ID;
+use serde::{Deserialize, Serialize};
+
+/*
+ * Completing an objective wins the game for its faction and that faction's allies
+ * Turns are counted in unit turns, as in Game
+ */
+#[derive (Debug)]
+#[derive (Clone)]
+#[derive (PartialEq)]
+#[derive (Deserialize, Serialize)]
+pub enum Objective {
+    Eliminate, // kill every enemy unit
+    Assassinate (ID), // unit
+    Hold (Vec<ID>, usize), // cities, turns
+    Survive (usize), // turns
+    Control (u8), // percentage of tiles
+}
@@ -1,54 +1,535 @@
-use crate::character::{FactionBuilder, Magic, Skill, UnitBuilder, Weapon};
-use crate::common::ID;
-use crate::dynamic::{Attribute, Effect, Modifier};
-use crate::map::{City, Location, Terrain, TileBuilder};
+use crate::character::{Class, ElementMatrix, FactionBuilder, Magic, MULTIPLIER_BASE, Requirement, Retaliation, Skill, UnitBuilder, Weapon};
+use crate::common::{ID, ID_UNINITIALISED, Objective, Target};
+use crate::dynamic::{Adjustment, Appliable, AppliableKind, Applier, Attribute, Effect, Modifier};
+use crate::dynamic::StatisticKind;
+use crate::event::{Condition, Consequence, Script};
+use crate::map::{City, Location, Terrain, TileBuilder, Weather, ZoneOfControl};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::path::Path;
+use std::rc::Rc;
 use super::debug;
 
 #[derive (Debug)]
+#[derive (Clone, Copy)]
+#[derive (PartialEq)]
+pub enum SceneError {
+    MismatchedId (&'static str, usize, ID), // object, index, ID
+    MissingReference (&'static str, ID, &'static str, ID), // object, ID, referenced object, referenced ID
+    InvalidObject (&'static str, ID, &'static str), // object, ID, reason
+    InvalidMap (&'static str), // reason
+    MismatchedTextures (&'static str, usize, usize), // textures, expected, actual
+}
+
+impl Display for SceneError {
+    fn fmt (&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            SceneError::MismatchedId (object, index, id) => write! (f, "{} at index {} has ID {}", object, index, id),
+            SceneError::MissingReference (object, id, reference, reference_id) => write! (f, "{} {} references missing {} {}", object, id, reference, reference_id),
+            SceneError::InvalidObject (object, id, reason) => write! (f, "{} {} is invalid: {}", object, id, reason),
+            SceneError::InvalidMap (reason) => write! (f, "Map is invalid: {}", reason),
+            SceneError::MismatchedTextures (textures, expected, actual) => write! (f, "Expected {} {} textures, found {}", expected, textures, actual),
+        }
+    }
+}
+
+impl Error for SceneError {}
+
+/*
+ * Percentages in a scene (multipliers, terrain bonuses, retaliation, control objectives) are out of MULTIPLIER_BASE, so 25 means 25%
+ * Unit statistics such as MRL and ORG are finer, in tenths of a percent, so 1000 means 100.0%
+ */
+#[derive (Debug)]
+#[derive (Clone)]
+#[derive (Deserialize, Serialize)]
 pub struct Scene {
     // Objects
-    modifiers: &'static [Modifier],
-    effects: &'static [Effect],
-    attributes: &'static [Attribute],
-    terrains: &'static [Terrain],
-    cities: &'static [City],
-    weapons: &'static [Weapon],
-    magics: &'static [Magic],
-    skills: &'static [Skill],
-    faction_builders: &'static [FactionBuilder],
-    unit_builders: &'static [UnitBuilder],
-    tile_builders: &'static [&'static [TileBuilder]],
-    unit_locations: &'static [Option<Location>],
+    modifiers: Vec<Modifier>,
+    effects: Vec<Effect>,
+    attributes: Vec<Attribute>,
+    terrains: Vec<Terrain>,
+    cities: Vec<City>,
+    weapons: Vec<Weapon>,
+    magics: Vec<Magic>,
+    skills: Vec<Skill>,
+    classes: Vec<Class>,
+    faction_builders: Vec<FactionBuilder>,
+    unit_builders: Vec<UnitBuilder>,
+    tile_builders: Vec<Vec<TileBuilder>>,
+    unit_locations: Vec<Option<Location>>,
+    #[serde (default)]
+    objectives: Vec<(ID, Objective)>, // faction, objective
+    #[serde (default)]
+    weathers: Vec<Weather>,
+    #[serde (default)]
+    weather_pattern: Vec<(ID, usize)>, // weather, turns
+    #[serde (default)]
+    scripts: Vec<Script>,
+    #[serde (default)]
+    retaliation: Retaliation,
+    #[serde (default)]
+    zone_of_control: ZoneOfControl,
+    #[serde (default)]
+    element_matrix: ElementMatrix,
     // Textures
-    textures_terrain: &'static [&'static str],
-    textures_unit: &'static [&'static str],
+    textures_terrain: Vec<String>,
+    textures_unit: Vec<String>,
 }
 
 impl Scene {
     #[allow (clippy::too_many_arguments)]
-    pub fn new (modifiers: &'static [Modifier], effects: &'static [Effect], attributes: &'static [Attribute], terrains: &'static [Terrain], cities: &'static [City], weapons: &'static [Weapon], magics: &'static [Magic], skills: &'static [Skill], faction_builders: &'static [FactionBuilder], unit_builders: &'static [UnitBuilder], tile_builders: &'static [&'static [TileBuilder]], unit_locations: &'static [Option<Location>], textures_terrain: &'static [&'static str], textures_unit: &'static [&'static str]) -> Self {
-        Self { modifiers, effects, attributes, terrains, cities, weapons, magics, skills, faction_builders, unit_builders, tile_builders, unit_locations, textures_terrain, textures_unit }
+    pub fn new (modifiers: Vec<Modifier>, effects: Vec<Effect>, attributes: Vec<Attribute>, terrains: Vec<Terrain>, cities: Vec<City>, weapons: Vec<Weapon>, magics: Vec<Magic>, skills: Vec<Skill>, classes: Vec<Class>, faction_builders: Vec<FactionBuilder>, unit_builders: Vec<UnitBuilder>, tile_builders: Vec<Vec<TileBuilder>>, unit_locations: Vec<Option<Location>>, objectives: Vec<(ID, Objective)>, weathers: Vec<Weather>, weather_pattern: Vec<(ID, usize)>, scripts: Vec<Script>, retaliation: Retaliation, zone_of_control: ZoneOfControl, element_matrix: ElementMatrix, textures_terrain: Vec<String>, textures_unit: Vec<String>) -> Self {
+        Self { modifiers, effects, attributes, terrains, cities, weapons, magics, skills, classes, faction_builders, unit_builders, tile_builders, unit_locations, objectives, weathers, weather_pattern, scripts, retaliation, zone_of_control, element_matrix, textures_terrain, textures_unit }
     }
 
-    #[allow (clippy::too_many_arguments)]
     pub fn debug () -> Self {
-        let modifiers: &[Modifier] = debug::objects::MODIFIERS;
-        let effects: &[Effect] = debug::objects::EFFECTS;
-        let attributes: &[Attribute] = debug::objects::ATTRIBUTES;
-        let terrains: &[Terrain] = debug::objects::TERRAINS;
-        let cities: &[City] = debug::objects::CITIES;
-        let weapons: &[Weapon] = debug::objects::WEAPONS;
-        let magics: &[Magic] = debug::objects::MAGICS;
-        let skills: &[Skill] = debug::objects::SKILLS;
-        let faction_builders: &[FactionBuilder] = debug::objects::FACTION_BUILDERS;
-        let unit_builders: &[UnitBuilder] = debug::objects::UNIT_BUILDERS;
-        let tile_builders: &[&[TileBuilder]] = debug::objects::TILE_BUILDERS;
-        let unit_locations: &[Option<Location>] = debug::objects::UNIT_LOCATIONS;
+        let modifiers: Vec<Modifier> = debug::objects::modifiers ();
+        let effects: Vec<Effect> = debug::objects::effects ();
+        let attributes: Vec<Attribute> = debug::objects::attributes ();
+        let terrains: Vec<Terrain> = debug::objects::terrains ();
+        let cities: Vec<City> = debug::objects::cities ();
+        let weapons: Vec<Weapon> = debug::objects::weapons ();
+        let magics: Vec<Magic> = debug::objects::magics ();
+        let skills: Vec<Skill> = debug::objects::skills ();
+        let classes: Vec<Class> = debug::objects::classes ();
+        let faction_builders: Vec<FactionBuilder> = debug::objects::faction_builders ();
+        let unit_builders: Vec<UnitBuilder> = debug::objects::unit_builders ();
+        let tile_builders: Vec<Vec<TileBuilder>> = debug::objects::tile_builders ();
+        let unit_locations: Vec<Option<Location>> = debug::objects::unit_locations ();
+        let objectives: Vec<(ID, Objective)> = debug::objects::objectives ();
+        let weathers: Vec<Weather> = debug::objects::weathers ();
+        let weather_pattern: Vec<(ID, usize)> = debug::objects::weather_pattern ();
+        let scripts: Vec<Script> = debug::objects::scripts ();
+        let retaliation: Retaliation = debug::objects::retaliation ();
+        let zone_of_control: ZoneOfControl = debug::objects::zone_of_control ();
+        let element_matrix: ElementMatrix = debug::objects::element_matrix ();
+
+        let textures_terrain: Vec<String> = debug::textures::TERRAINS.iter ().map (|t: &&str| t.to_string ()).collect ();
+        let textures_unit: Vec<String> = debug::textures::UNITS.iter ().map (|t: &&str| t.to_string ()).collect ();
+
+        Self { modifiers, effects, attributes, terrains, cities, weapons, magics, skills, classes, faction_builders, unit_builders, tile_builders, unit_locations, objectives, weathers, weather_pattern, scripts, retaliation, zone_of_control, element_matrix, textures_terrain, textures_unit }
+    }
+
+    // Cheap stand-in for scenes skipped by deserialisation until a load relinks them
+    pub fn placeholder () -> Rc<Self> {
+        let scene: Scene = Scene::new (Vec::new (), Vec::new (), Vec::new (), Vec::new (), Vec::new (), Vec::new (), Vec::new (), Vec::new (), Vec::new (), Vec::new (), Vec::new (), Vec::new (), Vec::new (), Vec::new (), Vec::new (), Vec::new (), Vec::new (), Retaliation::default (), ZoneOfControl::default (), ElementMatrix::default (), Vec::new (), Vec::new ());
+
+        Rc::new (scene)
+    }
+
+    pub fn load<P: AsRef<Path>> (path: P) -> Result<Self, Box<dyn Error>> {
+        let contents: String = fs::read_to_string (path)?;
+        let scene: Scene = ron::from_str (&contents)?;
+
+        if let Err (errors) = scene.validate () {
+            let errors: Vec<String> = errors.iter ().map (|e: &SceneError| e.to_string ()).collect ();
+
+            Err (errors.join ("\n").into ())
+        } else {
+            Ok (scene)
+        }
+    }
+
+    pub fn store<P: AsRef<Path>> (&self, path: P) -> Result<(), Box<dyn Error>> {
+        let contents: String = ron::ser::to_string_pretty (self, ron::ser::PrettyConfig::default ())?;
+
+        fs::write (path, contents)?;
+
+        Ok (())
+    }
+
+    // FNV-1a over the serialised scene, which is stable across runs and platforms
+    pub fn calculate_hash (&self) -> u64 {
+        const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+        let contents: String = ron::to_string (self)
+                .unwrap_or_else (|e| panic! ("Scene serialisation failed: {}", e));
+
+        contents.bytes ().fold (FNV_OFFSET, |hash: u64, b: u8| (hash ^ (b as u64)).wrapping_mul (FNV_PRIME))
+    }
+
+    pub fn validate (&self) -> Result<(), Vec<SceneError>> {
+        let mut errors: Vec<SceneError> = Vec::new ();
+
+        self.validate_ids (&mut errors);
+        self.validate_objects (&mut errors);
+        self.validate_references (&mut errors);
+        self.validate_map (&mut errors);
+        self.validate_scripts (&mut errors);
+        self.validate_textures (&mut errors);
+
+        if errors.is_empty () {
+            Ok (())
+        } else {
+            Err (errors)
+        }
+    }
+
+    fn validate_ids (&self, errors: &mut Vec<SceneError>) {
+        let ids: [(&'static str, Vec<ID>); 9] = [
+            ("Modifier", self.modifiers.iter ().map (|m: &Modifier| m.get_id ()).collect ()),
+            ("Effect", self.effects.iter ().map (|e: &Effect| e.get_id ()).collect ()),
+            ("Attribute", self.attributes.iter ().map (|a: &Attribute| a.get_id ()).collect ()),
+            ("Weapon", self.weapons.iter ().map (|w: &Weapon| w.get_id ()).collect ()),
+            ("Magic", self.magics.iter ().map (|m: &Magic| m.get_id ()).collect ()),
+            ("Skill", self.skills.iter ().map (|s: &Skill| s.get_id ()).collect ()),
+            ("Class", self.classes.iter ().map (|c: &Class| c.get_id ()).collect ()),
+            ("FactionBuilder", self.faction_builders.iter ().map (|f: &FactionBuilder| f.get_id ()).collect ()),
+            ("UnitBuilder", self.unit_builders.iter ().map (|u: &UnitBuilder| u.get_id ()).collect ()),
+        ];
+
+        for (object, ids) in ids {
+            for (index, id) in ids.into_iter ().enumerate () {
+                if index != id {
+                    errors.push (SceneError::MismatchedId (object, index, id));
+                }
+            }
+        }
+    }
+
+    fn validate_objects (&self, errors: &mut Vec<SceneError>) {
+        for modifier in self.modifiers.iter () {
+            if modifier.get_adjustments ().is_empty () {
+                errors.push (SceneError::InvalidObject ("Modifier", modifier.get_id (), "no adjustments"));
+            }
+        }
+
+        for effect in self.effects.iter () {
+            if effect.get_adjustments ().is_empty () {
+                errors.push (SceneError::InvalidObject ("Effect", effect.get_id (), "no adjustments"));
+            }
+        }
+
+        for (i, terrain) in self.terrains.iter ().enumerate () {
+            if terrain.get_resistance_magic () > MULTIPLIER_BASE {
+                errors.push (SceneError::InvalidObject ("Terrain", i, "magic resistance out of range"));
+            }
+
+            if !terrain.get_information ().is_valid () {
+                errors.push (SceneError::InvalidObject ("Terrain", i, "no description"));
+            }
+        }
+
+        for (i, city) in self.cities.iter ().enumerate () {
+            if city.get_population () == 0 || city.get_factories () == 0 || city.get_farms () == 0 {
+                errors.push (SceneError::InvalidObject ("City", i, "empty population, factories, or farms"));
+            }
+        }
+
+        for magic in self.magics.iter () {
+            if !matches! (magic.get_target (), Target::This | Target::Map) {
+                errors.push (SceneError::InvalidObject ("Magic", magic.get_id (), "target must be This or Map"));
+            }
+        }
+
+        for skill in self.skills.iter () {
+            if skill.get_appliables ().is_empty () {
+                errors.push (SceneError::InvalidObject ("Skill", skill.get_id (), "no appliables"));
+            }
+
+            if !matches! (skill.get_target (), Target::This | Target::Ally | Target::Allies) {
+                errors.push (SceneError::InvalidObject ("Skill", skill.get_id (), "target must be This, Ally, or Allies"));
+            }
+        }
+
+        for class in self.classes.iter () {
+            if !class.get_statistics ().is_valid () {
+                errors.push (SceneError::InvalidObject ("Class", class.get_id (), "statistics out of range"));
+            }
+        }
+
+        for unit_builder in self.unit_builders.iter () {
+            if let Some (class) = self.classes.get (unit_builder.get_class_id ()) {
+                if !unit_builder.get_weapon_ids ().iter ().all (|w: &ID| class.is_allowed (w)) {
+                    errors.push (SceneError::InvalidObject ("UnitBuilder", unit_builder.get_id (), "weapons not allowed by class"));
+                }
+            }
+
+            if let Some (heir_id) = unit_builder.get_heir_id () {
+                if heir_id == unit_builder.get_id () {
+                    errors.push (SceneError::InvalidObject ("UnitBuilder", unit_builder.get_id (), "heir is itself"));
+                } else if let Some (heir) = self.unit_builders.get (heir_id) {
+                    if heir.get_faction_id () != unit_builder.get_faction_id () {
+                        errors.push (SceneError::InvalidObject ("UnitBuilder", unit_builder.get_id (), "heir in another faction"));
+                    }
+                }
+            }
+        }
+
+        for (i, (_, objective)) in self.objectives.iter ().enumerate () {
+            match objective {
+                Objective::Control (percent) if (*percent as u16) > MULTIPLIER_BASE => errors.push (SceneError::InvalidObject ("Objective", i, "percentage out of range")),
+                Objective::Hold (city_ids, _) if city_ids.is_empty () => errors.push (SceneError::InvalidObject ("Objective", i, "no cities")),
+                _ => (),
+            }
+        }
+
+        for (i, weather) in self.weathers.iter ().enumerate () {
+            let is_tile: bool = weather.get_modifier_tile_id ()
+                    .and_then (|m: ID| self.modifiers.get (m))
+                    .is_none_or (|m: &Modifier| m.get_adjustments ().iter ().all (|a: &Adjustment| matches! (a.0, StatisticKind::Tile ( .. ))));
+            let is_unit: bool = weather.get_modifier_unit_id ()
+                    .and_then (|m: ID| self.modifiers.get (m))
+                    .is_none_or (|m: &Modifier| m.get_adjustments ().iter ().all (|a: &Adjustment| matches! (a.0, StatisticKind::Unit ( .. ))));
+
+            if !is_tile || !is_unit {
+                errors.push (SceneError::InvalidObject ("Weather", i, "modifiers must adjust tiles and units respectively"));
+            }
+        }
+
+        for (i, (_, turns)) in self.weather_pattern.iter ().enumerate () {
+            if *turns == 0 {
+                errors.push (SceneError::InvalidObject ("WeatherPattern", i, "no turns"));
+            }
+        }
+
+        if self.retaliation.get_multiplier () > MULTIPLIER_BASE {
+            errors.push (SceneError::InvalidObject ("Retaliation", 0, "multiplier out of range"));
+        }
+    }
+
+    fn validate_appliable (&self, appliable: AppliableKind, object: &'static str, id: ID, errors: &mut Vec<SceneError>) {
+        match appliable {
+            AppliableKind::Modifier (m) => if m >= self.modifiers.len () {
+                errors.push (SceneError::MissingReference (object, id, "Modifier", m));
+            }
+            AppliableKind::Effect (e) => if e >= self.effects.len () {
+                errors.push (SceneError::MissingReference (object, id, "Effect", e));
+            }
+            AppliableKind::Attribute (a) => if a >= self.attributes.len () {
+                errors.push (SceneError::MissingReference (object, id, "Attribute", a));
+            }
+        }
+    }
+
+    fn validate_references (&self, errors: &mut Vec<SceneError>) {
+        let mut validate_reference = |object: &'static str, id: ID, reference: &'static str, reference_id: ID, length: usize| {
+            if reference_id >= length {
+                errors.push (SceneError::MissingReference (object, id, reference, reference_id));
+            }
+        };
+
+        for modifier in self.modifiers.iter () {
+            if let Some (next_id) = modifier.get_next_id () {
+                validate_reference ("Modifier", modifier.get_id (), "Modifier", next_id, self.modifiers.len ());
+            }
+        }
+
+        for (i, terrain) in self.terrains.iter ().enumerate () {
+            if let Some (modifier_id) = terrain.get_modifier_id () {
+                validate_reference ("Terrain", i, "Modifier", modifier_id, self.modifiers.len ());
+            }
+        }
+
+        for (i, city) in self.cities.iter ().enumerate () {
+            if let Some (recruit_id) = city.get_recruit_id () {
+                validate_reference ("City", i, "UnitBuilder", recruit_id, self.unit_builders.len ());
+            }
+        }
+
+        for class in self.classes.iter () {
+            let id: ID = class.get_id ();
+
+            for weapon_id in class.get_weapon_ids () {
+                validate_reference ("Class", id, "Weapon", *weapon_id, self.weapons.len ());
+            }
+
+            for (terrain_id, _) in class.get_multipliers_terrain () {
+                validate_reference ("Class", id, "Terrain", *terrain_id, self.terrains.len ());
+            }
+
+            for (class_id, requirement) in class.get_promotions () {
+                validate_reference ("Class", id, "Class", *class_id, self.classes.len ());
+
+                if let Requirement::Weapon (weapon_id) = requirement {
+                    validate_reference ("Class", id, "Weapon", *weapon_id, self.weapons.len ());
+                }
+            }
+        }
+
+        for faction_builder in self.faction_builders.iter () {
+            for ally_id in faction_builder.get_allies () {
+                validate_reference ("FactionBuilder", faction_builder.get_id (), "FactionBuilder", *ally_id, self.faction_builders.len ());
+            }
+        }
+
+        for unit_builder in self.unit_builders.iter () {
+            let id: ID = unit_builder.get_id ();
+
+            validate_reference ("UnitBuilder", id, "Class", unit_builder.get_class_id (), self.classes.len ());
+
+            for weapon_id in unit_builder.get_weapon_ids () {
+                validate_reference ("UnitBuilder", id, "Weapon", *weapon_id, self.weapons.len ());
+            }
+
+            if let Some (skill_passive_id) = unit_builder.get_skill_passive_id () {
+                validate_reference ("UnitBuilder", id, "Skill", skill_passive_id, self.skills.len ());
+            }
+
+            for skill_id in unit_builder.get_skill_ids () {
+                validate_reference ("UnitBuilder", id, "Skill", *skill_id, self.skills.len ());
+            }
+
+            validate_reference ("UnitBuilder", id, "FactionBuilder", unit_builder.get_faction_id (), self.faction_builders.len ());
+
+            if let Some (leader_id) = unit_builder.get_leader_id () {
+                if leader_id < ID_UNINITIALISED {
+                    validate_reference ("UnitBuilder", id, "UnitBuilder", leader_id, self.unit_builders.len ());
+                }
+            }
+
+            if let Some (heir_id) = unit_builder.get_heir_id () {
+                validate_reference ("UnitBuilder", id, "UnitBuilder", heir_id, self.unit_builders.len ());
+            }
+        }
+
+        for (i, row) in self.tile_builders.iter ().enumerate () {
+            for (j, tile_builder) in row.iter ().enumerate () {
+                let id: ID = i * row.len () + j;
+
+                validate_reference ("TileBuilder", id, "Terrain", tile_builder.get_terrain_id (), self.terrains.len ());
+
+                if let Some (city_id) = tile_builder.get_city_id () {
+                    validate_reference ("TileBuilder", id, "City", city_id, self.cities.len ());
+                }
+            }
+        }
+
+        for (i, (faction_id, objective)) in self.objectives.iter ().enumerate () {
+            validate_reference ("Objective", i, "FactionBuilder", *faction_id, self.faction_builders.len ());
+
+            match objective {
+                Objective::Assassinate (unit_id) => validate_reference ("Objective", i, "UnitBuilder", *unit_id, self.unit_builders.len ()),
+                Objective::Hold (city_ids, _) => for city_id in city_ids {
+                    validate_reference ("Objective", i, "City", *city_id, self.cities.len ());
+                }
+                _ => (),
+            }
+        }
+
+        for (i, weather) in self.weathers.iter ().enumerate () {
+            for modifier_id in [weather.get_modifier_tile_id (), weather.get_modifier_unit_id ()].into_iter ().flatten () {
+                validate_reference ("Weather", i, "Modifier", modifier_id, self.modifiers.len ());
+            }
+        }
+
+        for (i, (weather_id, _)) in self.weather_pattern.iter ().enumerate () {
+            validate_reference ("WeatherPattern", i, "Weather", *weather_id, self.weathers.len ());
+        }
+
+        for (i, script) in self.scripts.iter ().enumerate () {
+            match script.get_condition () {
+                Condition::UnitDied (Some (unit_id)) | Condition::UnitMoved (Some (unit_id), _) => validate_reference ("Script", i, "UnitBuilder", unit_id, self.unit_builders.len ()),
+                Condition::CityCaptured (city_id, faction_id) => {
+                    validate_reference ("Script", i, "City", city_id, self.cities.len ());
+
+                    if let Some (faction_id) = faction_id {
+                        validate_reference ("Script", i, "FactionBuilder", faction_id, self.faction_builders.len ());
+                    }
+                }
+                Condition::FactionEliminated (faction_id) => validate_reference ("Script", i, "FactionBuilder", faction_id, self.faction_builders.len ()),
+                _ => (),
+            }
+
+            for consequence in script.get_consequences () {
+                match consequence {
+                    Consequence::Spawn (unit_id, _) => validate_reference ("Script", i, "UnitBuilder", *unit_id, self.unit_builders.len ()),
+                    Consequence::Ally (faction_id_first, faction_id_second, _) => {
+                        validate_reference ("Script", i, "FactionBuilder", *faction_id_first, self.faction_builders.len ());
+                        validate_reference ("Script", i, "FactionBuilder", *faction_id_second, self.faction_builders.len ());
+                    }
+                    Consequence::Modify (modifier_id, _, _) => validate_reference ("Script", i, "Modifier", *modifier_id, self.modifiers.len ()),
+                    Consequence::Dialogue ( .. ) => (),
+                }
+            }
+        }
+
+        for class_id in self.zone_of_control.get_class_ids_exempt () {
+            validate_reference ("ZoneOfControl", 0, "Class", *class_id, self.classes.len ());
+        }
+
+        for skill_id in self.zone_of_control.get_skill_ids_exempt () {
+            validate_reference ("ZoneOfControl", 0, "Skill", *skill_id, self.skills.len ());
+        }
+
+        for attribute in self.attributes.iter () {
+            self.validate_appliable (attribute.get_kind (), "Attribute", attribute.get_id (), errors);
+        }
+
+        for magic in self.magics.iter () {
+            self.validate_appliable (magic.get_appliable (), "Magic", magic.get_id (), errors);
+        }
+
+        for skill in self.skills.iter () {
+            for appliable in skill.get_appliables () {
+                self.validate_appliable (*appliable, "Skill", skill.get_id (), errors);
+            }
+        }
+    }
+
+    fn validate_map (&self, errors: &mut Vec<SceneError>) {
+        let length: usize = self.tile_builders.len ();
+        let width: usize = self.tile_builders.first ().map_or (0, |r: &Vec<TileBuilder>| r.len ());
+        let mut locations: HashSet<Location> = HashSet::new ();
+
+        if length == 0 || width == 0 {
+            errors.push (SceneError::InvalidMap ("no tiles"));
+        }
+
+        if self.tile_builders.iter ().any (|r: &Vec<TileBuilder>| r.len () != width) {
+            errors.push (SceneError::InvalidMap ("rows have different widths"));
+        }
+
+        if self.unit_locations.len () != self.unit_builders.len () {
+            errors.push (SceneError::InvalidMap ("unit locations do not match unit builders"));
+        }
+
+        for (unit_id, location) in self.unit_locations.iter ().enumerate () {
+            if let Some (location) = location {
+                if location.0 >= length || location.1 >= width {
+                    errors.push (SceneError::InvalidObject ("UnitBuilder", unit_id, "location out of bounds"));
+                } else if !locations.insert (*location) {
+                    errors.push (SceneError::InvalidObject ("UnitBuilder", unit_id, "location already occupied"));
+                }
+            }
+        }
+    }
+
+    fn validate_scripts (&self, errors: &mut Vec<SceneError>) {
+        let length: usize = self.tile_builders.len ();
+        let width: usize = self.tile_builders.first ().map_or (0, |r: &Vec<TileBuilder>| r.len ());
+
+        for (i, script) in self.scripts.iter ().enumerate () {
+            let mut locations: Vec<Location> = Vec::new ();
+
+            if let Condition::UnitMoved (_, location) = script.get_condition () {
+                locations.push (location);
+            }
 
-        let textures_terrain: &[&str] = debug::textures::TERRAINS;
-        let textures_unit: &[&str] = debug::textures::UNITS;
+            for consequence in script.get_consequences () {
+                if let Consequence::Spawn (_, location) | Consequence::Modify (_, location, _) = consequence {
+                    locations.push (*location);
+                }
+            }
 
-        Self { modifiers, effects, attributes, terrains, cities, weapons, magics, skills, faction_builders, unit_builders, tile_builders, unit_locations, textures_terrain, textures_unit }
+            if locations.iter ().any (|l: &Location| l.0 >= length || l.1 >= width) {
+                errors.push (SceneError::InvalidObject ("Script", i, "location out of bounds"));
+            }
+        }
+    }
+
+    fn validate_textures (&self, errors: &mut Vec<SceneError>) {
+        if self.textures_terrain.len () != self.terrains.len () {
+            errors.push (SceneError::MismatchedTextures ("terrain", self.terrains.len (), self.textures_terrain.len ()));
+        }
+
+        if self.textures_unit.len () != self.unit_builders.len () {
+            errors.push (SceneError::MismatchedTextures ("unit", self.unit_builders.len (), self.textures_unit.len ()));
+        }
     }
 
     pub fn get_modifier (&self, id: &ID) -> &Modifier {
@@ -103,6 +584,12 @@ impl Scene {
         &self.skills[*id]
     }
 
+    pub fn get_class (&self, id: &ID) -> &Class {
+        assert! (*id < self.classes.len ());
+
+        &self.classes[*id]
+    }
+
     // pub fn skills_iter (&self) -> impl Iterator<Item = &Skill> {
     //     self.skills.iter ()
     // }
@@ -127,8 +614,8 @@ impl Scene {
         self.unit_builders.iter ()
     }
 
-    pub fn get_tile_builders (&self) -> &[&[TileBuilder]] {
-        self.tile_builders
+    pub fn get_tile_builders (&self) -> &[Vec<TileBuilder>] {
+        &self.tile_builders
     }
 
     pub fn get_unit_location (&self, unit_id: &ID) -> &Option<Location> {
@@ -141,33 +628,164 @@ impl Scene {
         self.unit_locations.iter ()
     }
 
-    pub fn textures_terrain_iter (&self) -> impl Iterator<Item = &&str> {
+    pub fn get_objectives (&self) -> &[(ID, Objective)] {
+        &self.objectives
+    }
+
+    pub fn get_retaliation (&self) -> &Retaliation {
+        &self.retaliation
+    }
+
+    pub fn get_zone_of_control (&self) -> &ZoneOfControl {
+        &self.zone_of_control
+    }
+
+    pub fn get_element_matrix (&self) -> &ElementMatrix {
+        &self.element_matrix
+    }
+
+    pub fn get_weather (&self, id: &ID) -> &Weather {
+        assert! (*id < self.weathers.len ());
+
+        &self.weathers[*id]
+    }
+
+    pub fn get_script (&self, id: &ID) -> &Script {
+        assert! (*id < self.scripts.len ());
+
+        &self.scripts[*id]
+    }
+
+    pub fn scripts_iter (&self) -> impl Iterator<Item = &Script> {
+        self.scripts.iter ()
+    }
+
+    // Patterns repeat once every weather has run its course
+    pub fn find_weather_id (&self, number_turns: usize) -> Option<ID> {
+        let length: usize = self.weather_pattern.iter ().map (|(_, t): &(ID, usize)| t).sum ();
+        let mut number_turns: usize = number_turns.checked_rem (length)?;
+
+        for (weather_id, turns) in self.weather_pattern.iter () {
+            if number_turns < *turns {
+                return Some (*weather_id)
+            }
+
+            number_turns -= turns;
+        }
+
+        None
+    }
+
+    pub fn textures_terrain_iter (&self) -> impl Iterator<Item = &String> {
         self.textures_terrain.iter ()
     }
 
-    pub fn textures_unit_iter (&self) -> impl Iterator<Item = &&str> {
+    pub fn textures_unit_iter (&self) -> impl Iterator<Item = &String> {
         self.textures_unit.iter ()
     }
 }
 
 impl Default for Scene {
     fn default () -> Self {
-        let modifiers: &[Modifier] = debug::objects::MODIFIERS;
-        let effects: &[Effect] = debug::objects::EFFECTS;
-        let attributes: &[Attribute] = debug::objects::ATTRIBUTES;
-        let terrains: &[Terrain] = debug::objects::TERRAINS;
-        let cities: &[City] = debug::objects::CITIES;
-        let weapons: &[Weapon] = debug::objects::WEAPONS;
-        let magics: &[Magic] = debug::objects::MAGICS;
-        let skills: &[Skill] = debug::objects::SKILLS;
-        let faction_builders: &[FactionBuilder] = debug::objects::FACTION_BUILDERS;
-        let unit_builders: &[UnitBuilder] = debug::objects::UNIT_BUILDERS;
-        let tile_builders: &[&[TileBuilder]] = debug::objects::TILE_BUILDERS;
-        let unit_locations: &[Option<Location>] = debug::objects::UNIT_LOCATIONS;
-
-        let textures_terrain: &[&str] = debug::textures::TERRAINS;
-        let textures_unit: &[&str] = debug::textures::UNITS;
-
-        Self { modifiers, effects, attributes, terrains, cities, weapons, magics, skills, faction_builders, unit_builders, tile_builders, unit_locations, textures_terrain, textures_unit }
+        Self::debug ()
+    }
+}
+
+#[cfg (test)]
+mod tests {
+    use super::*;
+    use crate::common::Information;
+    use crate::map::{WeatherKind, ZoneRule};
+    use crate::character::{RetaliationOrder, Succession};
+
+    #[test]
+    fn scene_load () {
+        let scene: Scene = Scene::load ("assets/scenes/debug.ron").unwrap ();
+        let debug: Scene = Scene::debug ();
+
+        assert_eq! (format! ("{:?}", scene), format! ("{:?}", debug));
+        assert! (Scene::load ("assets/scenes/missing.ron").is_err ());
+    }
+
+    #[test]
+    fn scene_calculate_hash () {
+        let mut scene: Scene = Scene::debug ();
+        let hash_0: u64 = scene.calculate_hash ();
+
+        assert_eq! (hash_0, Scene::debug ().calculate_hash ());
+        scene.unit_locations[1] = Some ((1, 1));
+        assert_ne! (hash_0, scene.calculate_hash ());
+    }
+
+    #[test]
+    fn scene_validate () {
+        let mut scene: Scene = Scene::debug ();
+
+        assert! (scene.validate ().is_ok ());
+
+        scene.cities.push (City::new (10, 1, 1, Some (5)));
+        scene.faction_builders.push (FactionBuilder::new (4, &[5], Succession::Organisation));
+        scene.textures_unit.pop ();
+        scene.unit_locations[1] = Some ((0, 0));
+        scene.objectives.push ((0, Objective::Assassinate (5)));
+        scene.objectives.push ((0, Objective::Control (101)));
+        scene.objectives.push ((0, Objective::Hold (Vec::new (), 1)));
+        scene.weathers.push (Weather::new (WeatherKind::Fog, Some (11), Some (20)));
+        scene.weather_pattern.push ((4, 0));
+        scene.scripts.push (Script::new (Condition::FactionEliminated (5), vec![Consequence::Spawn (1, (2, 0))], false));
+        scene.classes.push (Class::new (5, *scene.classes[0].get_statistics (), &[3], [false, false, false], &[(3, 100)], &[(6, Requirement::Level (1))], None));
+        scene.unit_builders[0] = UnitBuilder::new (0, 2, &[0], Some (1), &[0, 2, 3], 0, None, Some (2), [2, 2, 2, 1, 50]);
+        scene.unit_builders[2] = UnitBuilder::new (2, 3, &[1, 2], Some (1), &[0, 2, 3], 1, None, Some (2), [2, 2, 2, 1, 50]);
+        scene.unit_builders[4] = UnitBuilder::new (4, 1, &[1], None, &[], 2, None, Some (5), [0; 5]);
+        scene.retaliation = Retaliation::new (RetaliationOrder::After, 150);
+        scene.zone_of_control = ZoneOfControl::new (ZoneRule::Stop, &[6], &[7]);
+        scene.terrains[0] = Terrain::new (None, 1, 0, 150, 0, 0, 0, None, Information::default ());
+        scene.terrains[1] = Terrain::new (None, 1, 0, 0, 0, 0, 0, None, ron::from_str ("(name: \"\", descriptions: [])").unwrap ());
+
+        let errors: Vec<SceneError> = scene.validate ().unwrap_err ();
+
+        assert_eq! (errors.len (), 26);
+        assert! (errors.contains (&SceneError::MissingReference ("City", 4, "UnitBuilder", 5)));
+        assert! (errors.contains (&SceneError::MismatchedId ("FactionBuilder", 3, 4)));
+        assert! (errors.contains (&SceneError::MissingReference ("FactionBuilder", 4, "FactionBuilder", 5)));
+        assert! (errors.contains (&SceneError::InvalidObject ("UnitBuilder", 1, "location already occupied")));
+        assert! (errors.contains (&SceneError::MismatchedTextures ("unit", 5, 4)));
+        assert! (errors.contains (&SceneError::MissingReference ("Objective", 3, "UnitBuilder", 5)));
+        assert! (errors.contains (&SceneError::InvalidObject ("Objective", 4, "percentage out of range")));
+        assert! (errors.contains (&SceneError::InvalidObject ("Objective", 5, "no cities")));
+        assert! (errors.contains (&SceneError::InvalidObject ("Weather", 3, "modifiers must adjust tiles and units respectively")));
+        assert! (errors.contains (&SceneError::MissingReference ("Weather", 3, "Modifier", 20)));
+        assert! (errors.contains (&SceneError::InvalidObject ("WeatherPattern", 3, "no turns")));
+        assert! (errors.contains (&SceneError::MissingReference ("WeatherPattern", 3, "Weather", 4)));
+        assert! (errors.contains (&SceneError::MissingReference ("Script", 3, "FactionBuilder", 5)));
+        assert! (errors.contains (&SceneError::InvalidObject ("Script", 3, "location out of bounds")));
+        assert! (errors.contains (&SceneError::MissingReference ("Class", 5, "Weapon", 3)));
+        assert! (errors.contains (&SceneError::MissingReference ("Class", 5, "Terrain", 3)));
+        assert! (errors.contains (&SceneError::MissingReference ("Class", 5, "Class", 6)));
+        assert! (errors.contains (&SceneError::InvalidObject ("UnitBuilder", 4, "weapons not allowed by class")));
+        assert! (errors.contains (&SceneError::InvalidObject ("UnitBuilder", 0, "heir in another faction")));
+        assert! (errors.contains (&SceneError::InvalidObject ("UnitBuilder", 2, "heir is itself")));
+        assert! (errors.contains (&SceneError::MissingReference ("UnitBuilder", 4, "UnitBuilder", 5)));
+        assert! (errors.contains (&SceneError::InvalidObject ("Retaliation", 0, "multiplier out of range")));
+        assert! (errors.contains (&SceneError::MissingReference ("ZoneOfControl", 0, "Class", 6)));
+        assert! (errors.contains (&SceneError::MissingReference ("ZoneOfControl", 0, "Skill", 7)));
+        assert! (errors.contains (&SceneError::InvalidObject ("Terrain", 0, "magic resistance out of range")));
+        assert! (errors.contains (&SceneError::InvalidObject ("Terrain", 1, "no description")));
+    }
+
+    #[test]
+    fn scene_find_weather_id () {
+        let mut scene: Scene = Scene::debug ();
+
+        assert_eq! (scene.find_weather_id (0), Some (0));
+        assert_eq! (scene.find_weather_id (11), Some (0));
+        assert_eq! (scene.find_weather_id (12), Some (1));
+        assert_eq! (scene.find_weather_id (16), Some (2));
+        // Test repeated pattern
+        assert_eq! (scene.find_weather_id (20), Some (0));
+        assert_eq! (scene.find_weather_id (32), Some (1));
+        // Test empty pattern
+        scene.weather_pattern.clear ();
+        assert_eq! (scene.find_weather_id (0), None);
     }
 }
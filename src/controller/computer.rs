@@ -0,0 +1,418 @@
+use super::Controller;
+use crate::character::{Faction, Magic, Skill, Tool, Unit, UnitStatistic, UnitStatistics, Weapon};
+use crate::common::{ID, Target};
+use crate::dynamic::Applier;
+use crate::map::{Area, Direction, DIRECTIONS, Grid, Location, Mover, Positioning, Reachability, Search, Terrain, ThreatMap};
+use crate::system::{Action, Choice, Decision, Game};
+use std::collections::HashSet;
+
+const DEPTH_DEFAULT: usize = 4; // turns simulated after a decision
+const FACTOR_TERRITORY: i64 = 10; // worth of a tile in statistic points
+
+// Cheapest movements to every location reachable by mover, starting with its own location
+fn find_paths (grid: &Grid, mover: &Mover, mov: u16) -> Vec<(Location, Vec<Direction>)> {
+    let reachability: Reachability = grid.find_unit_reachable (mover, mov);
+    let mut locations: Vec<(u16, Location)> = reachability.locations_iter ().map (|l: Location|
+        (reachability.get_cost (&l).unwrap_or_else (|| panic! ("Cost not found for location {:?}", l)), l)
+    ).collect ();
+
+    // Cheapest first keeps decisions deterministic
+    locations.sort ();
+
+    locations.into_iter ().map (|(_, l): (u16, Location)| {
+        let movements: Vec<Direction> = reachability.find_movements (&l)
+                .unwrap_or_else (|| panic! ("Movements not found for location {:?}", l));
+
+        (l, movements)
+    }).collect ()
+}
+
+// Enemies among unit_ids that faction_id can see
+fn filter_visible (game: &Game, unit_ids: &[ID], faction_id: ID) -> Vec<ID> {
+    game.filter_unit_allegiance (unit_ids, faction_id, false).into_iter ().filter (|e: &ID| {
+        let location: &Location = game.get_grid ().get_unit_location (e)
+                .unwrap_or_else (|| panic! ("Location not found for unit {}", e));
+
+        game.is_visible (faction_id, location)
+    }).collect ()
+}
+
+// Every way to attack from location, with the visible enemies hit by each
+fn find_attacks (game: &Game, unit_id: ID, location: &Location) -> Vec<(Choice, Vec<ID>)> {
+    let (target, area, range): (Target, Area, u8) = {
+        let weapon: &Weapon = game.get_unit (&unit_id).get_weapon ();
+
+        (weapon.get_target (), weapon.get_area (), weapon.get_range ())
+    };
+    let faction_id: ID = game.get_unit (&unit_id).get_faction_id ();
+    let mut attacks: Vec<(Choice, Vec<ID>)> = Vec::new ();
+
+    match area {
+        Area::Single => for potential_id in game.find_units_range_from (unit_id, location, target, area, range) {
+            attacks.push ((Choice::Unit (potential_id), vec![potential_id]));
+        }
+        Area::Radial (r) => for potential_id in game.find_units_range_from (unit_id, location, target, area, range) {
+            let potential_location: &Location = game.get_grid ().get_unit_location (&potential_id)
+                    .unwrap_or_else (|| panic! ("Location not found for unit {}", potential_id));
+            let target_ids: Vec<ID> = game.get_grid ().find_units (potential_location, Search::Radial (r));
+            let target_ids: Vec<ID> = filter_visible (game, &target_ids, faction_id);
+
+            attacks.push ((Choice::Unit (potential_id), target_ids));
+        }
+        Area::Path (w) => for direction in DIRECTIONS {
+            let target_ids: Vec<ID> = game.get_grid ().find_units (location, Search::Path (w, range, direction));
+            let target_ids: Vec<ID> = filter_visible (game, &target_ids, faction_id);
+
+            if !target_ids.is_empty () {
+                attacks.push ((Choice::Direction (direction), target_ids));
+            }
+        }
+    }
+
+    attacks
+}
+
+// Where faction_id can see or last saw each of its enemies
+fn find_enemy_locations_seen (game: &Game, faction_id: ID) -> Vec<Location> {
+    let faction: &Faction = game.get_faction (&faction_id);
+    let enemy_ids: Vec<ID> = game.filter_unit_allegiance (&game.find_units_alive (), faction_id, false);
+
+    enemy_ids.iter ().filter_map (|e: &ID| faction.get_unit_location_seen (e).copied ()).collect ()
+}
+
+#[derive (Debug)]
+pub struct Inactive;
+
+impl Controller for Inactive {
+    fn decide (&mut self, _game: &Game, _unit_id: ID) -> Decision {
+        Decision::default ()
+    }
+}
+
+#[derive (Debug)]
+pub struct Computer;
+
+impl Computer {
+    fn find_attack (&self, game: &Game, unit_id: ID, paths: &[(Location, Vec<Direction>)]) -> Option<Decision> {
+        let statistics: UnitStatistics = game.get_unit (&unit_id).get_statistics ();
+        let weapon: Weapon = *game.get_unit (&unit_id).get_weapon ();
+        let mut damage_best: u16 = 0;
+        let mut decision: Option<Decision> = None;
+
+        for (location, movements) in paths {
+            for (choice, target_ids) in find_attacks (game, unit_id, location) {
+                let damage: u16 = target_ids.iter ().map (|t: &ID| {
+                    let statistics_target: UnitStatistics = game.get_unit (t).get_statistics ();
+                    let positioning: Positioning = game.find_positioning (unit_id, location, *t);
+                    let terrain_target: &Terrain = game.get_scene ().get_terrain (&game.find_unit_terrain_id (t));
+                    let (damage_mrl, damage_hlt, damage_spl): (u16, u16, u16) = UnitStatistics::calculate_damage (&statistics, &statistics_target, &weapon, &positioning, terrain_target);
+
+                    damage_mrl.saturating_add (damage_hlt).saturating_add (damage_spl)
+                }).fold (0, u16::saturating_add);
+
+                if damage > damage_best {
+                    damage_best = damage;
+                    decision = Some (Decision::new (movements.clone (), Action::Attack, None, Some (choice)));
+                }
+            }
+        }
+
+        decision
+    }
+
+    fn find_approach (&self, game: &Game, unit_id: ID, paths: &[(Location, Vec<Direction>)]) -> Vec<Direction> {
+        let faction_id: ID = game.get_unit (&unit_id).get_faction_id ();
+        let enemy_locations: Vec<Location> = find_enemy_locations_seen (game, faction_id);
+        let mut distance_best: usize = usize::MAX;
+        let mut movements_best: Vec<Direction> = Vec::new ();
+
+        for (location, movements) in paths {
+            let distance: usize = enemy_locations.iter ().map (|l: &Location|
+                location.0.abs_diff (l.0) + location.1.abs_diff (l.1)
+            ).min ().unwrap_or (usize::MAX);
+
+            if distance < distance_best {
+                distance_best = distance;
+                movements_best.clone_from (movements);
+            }
+        }
+
+        movements_best
+    }
+
+    fn find_retreat (&self, game: &Game, unit_id: ID, paths: &[(Location, Vec<Direction>)]) -> Vec<Direction> {
+        let faction_id: ID = game.get_unit (&unit_id).get_faction_id ();
+        let threat_map: ThreatMap = game.find_threats (faction_id, Some (faction_id));
+
+        // Paths are sorted by cost, so ties favour staying close
+        paths.iter ().min_by_key (|(l, _): &&(Location, Vec<Direction>)|
+            threat_map.get_unit_ids (l).len ()
+        ).map_or (Vec::new (), |(_, m): &(Location, Vec<Direction>)| m.clone ())
+    }
+}
+
+impl Controller for Computer {
+    fn decide (&mut self, game: &Game, unit_id: ID) -> Decision {
+        let mov: u16 = game.get_unit (&unit_id).get_statistic (UnitStatistic::MOV).0;
+        let paths: Vec<(Location, Vec<Direction>)> = find_paths (game.get_grid (), &game.find_mover (unit_id), mov);
+
+        if !game.get_unit (&unit_id).is_retreat () {
+            if let Some (decision) = self.find_attack (game, unit_id, &paths) {
+                return decision
+            }
+        }
+
+        let movements: Vec<Direction> = if game.get_unit (&unit_id).is_retreat () {
+            self.find_retreat (game, unit_id, &paths)
+        } else {
+            self.find_approach (game, unit_id, &paths)
+        };
+
+        Decision::new (movements, Action::Wait, None, None)
+    }
+}
+
+// Positive scores favour faction_id and its allies
+fn evaluate (game: &Game, faction_id: ID) -> i64 {
+    let faction: &Faction = game.get_faction (&faction_id);
+    let number_factions: usize = game.get_scene ().faction_builders_iter ().count ();
+    let mut score: i64 = 0;
+
+    for unit_id in game.find_units_alive () {
+        let unit: &Unit = game.get_unit (&unit_id);
+        let mrl: i64 = unit.get_statistic (UnitStatistic::MRL).0 as i64;
+        let hlt: i64 = unit.get_statistic (UnitStatistic::HLT).0 as i64;
+        let spl: i64 = unit.get_statistic (UnitStatistic::SPL).0 as i64;
+        let value: i64 = mrl + hlt + spl;
+
+        if faction.is_ally (&unit.get_faction_id ()) {
+            score += value;
+        } else {
+            score -= value;
+        }
+    }
+
+    for faction_id_other in 0 .. number_factions {
+        let territory: i64 = game.get_grid ().get_faction_locations (&faction_id_other)
+                .map_or (0, |l: &HashSet<Location>| l.len () as i64);
+
+        if faction.is_ally (&faction_id_other) {
+            score += territory * FACTOR_TERRITORY;
+        } else {
+            score -= territory * FACTOR_TERRITORY;
+        }
+    }
+
+    score
+}
+
+#[derive (Debug)]
+pub struct Lookahead {
+    depth: usize,
+}
+
+impl Lookahead {
+    pub fn new (depth: usize) -> Self {
+        Self { depth }
+    }
+
+    fn find_decisions (&self, game: &Game, unit_id: ID) -> Vec<Decision> {
+        let unit: &Unit = game.get_unit (&unit_id);
+        let location: Location = *game.get_grid ().get_unit_location (&unit_id)
+                .unwrap_or_else (|| panic! ("Location not found for unit {}", unit_id));
+        let faction_id: ID = unit.get_faction_id ();
+        let mov: u16 = unit.get_statistic (UnitStatistic::MOV).0;
+        let paths: Vec<(Location, Vec<Direction>)> = find_paths (game.get_grid (), &game.find_mover (unit_id), mov);
+        let mut decisions: Vec<Decision> = Vec::new ();
+
+        for (_, movements) in paths.iter () {
+            decisions.push (Decision::new (movements.clone (), Action::Wait, None, None));
+        }
+
+        if !unit.is_retreat () {
+            for (location, movements) in paths.iter () {
+                for (choice, _) in find_attacks (game, unit_id, location) {
+                    decisions.push (Decision::new (movements.clone (), Action::Attack, None, Some (choice)));
+                }
+            }
+        }
+
+        if unit.is_rout () {
+            return decisions
+        }
+
+        for skill_id in unit.get_skill_ids_actionable () {
+            let skill: &Skill = game.get_scene ().get_skill (&skill_id);
+
+            if let Area::Path ( .. ) = skill.get_area () {
+                for direction in DIRECTIONS {
+                    decisions.push (Decision::new (Vec::new (), Action::Skill, Some (skill_id), Some (Choice::Direction (direction))));
+                }
+            } else {
+                for potential_id in game.find_units_range (unit_id, skill.get_target (), skill.get_area (), skill.get_range ()) {
+                    decisions.push (Decision::new (Vec::new (), Action::Skill, Some (skill_id), Some (Choice::Unit (potential_id))));
+                }
+            }
+        }
+
+        for magic_id in unit.get_magic_ids () {
+            let magic: &Magic = game.get_scene ().get_magic (magic_id);
+
+            match (magic.get_target (), magic.get_area ()) {
+                (Target::Map, Area::Path ( .. )) => for direction in DIRECTIONS {
+                    decisions.push (Decision::new (Vec::new (), Action::Magic, Some (*magic_id), Some (Choice::Direction (direction))));
+                }
+                // Only aim at visible enemies, as every tile in range would be too many candidates
+                (Target::Map, _) => {
+                    let enemy_ids: Vec<ID> = game.get_grid ().find_units (&location, Search::Radial (magic.get_range ()));
+                    let enemy_ids: Vec<ID> = filter_visible (game, &enemy_ids, faction_id);
+
+                    for enemy_id in enemy_ids {
+                        let enemy_location: Location = *game.get_grid ().get_unit_location (&enemy_id)
+                                .unwrap_or_else (|| panic! ("Location not found for unit {}", enemy_id));
+
+                        decisions.push (Decision::new (Vec::new (), Action::Magic, Some (*magic_id), Some (Choice::Location (enemy_location))));
+                    }
+                }
+                _ => decisions.push (Decision::new (Vec::new (), Action::Magic, Some (*magic_id), None)),
+            }
+        }
+
+        decisions
+    }
+
+    fn simulate (&self, game: &Game, decision: &Decision) -> Game {
+        let mut game: Game = game.fork ();
+
+        decision.play (&mut game);
+
+        // Everyone else is assumed to play greedily
+        for _ in 0 .. self.depth {
+            if game.is_over () {
+                break
+            }
+
+            let unit_id: ID = game.get_unit_id_active ();
+            let decision: Decision = Computer.decide (&game, unit_id);
+
+            decision.play (&mut game);
+        }
+
+        game
+    }
+}
+
+impl Default for Lookahead {
+    fn default () -> Self {
+        Self::new (DEPTH_DEFAULT)
+    }
+}
+
+impl Controller for Lookahead {
+    fn decide (&mut self, game: &Game, unit_id: ID) -> Decision {
+        let faction_id: ID = game.get_unit (&unit_id).get_faction_id ();
+        let mut decision_best: Decision = Computer.decide (game, unit_id);
+        let mut score_best: i64 = evaluate (&self.simulate (game, &decision_best), faction_id);
+
+        for decision in self.find_decisions (game, unit_id) {
+            let score: i64 = evaluate (&self.simulate (game, &decision), faction_id);
+
+            if score > score_best {
+                decision_best = decision;
+                score_best = score;
+            }
+        }
+
+        decision_best
+    }
+}
+
+#[cfg (test)]
+mod tests {
+    use super::*;
+    use crate::common::Scene;
+    use crate::tests::generate_game;
+    use std::sync::mpsc;
+
+    #[test]
+    fn computer_find_paths () {
+        let game = generate_game ();
+        let paths = find_paths (game.get_grid (), &game.find_mover (0), 10);
+
+        assert_eq! (paths[0], ((0, 0), vec![]));
+        assert! (paths.contains (&((0, 1), vec![Direction::Right])));
+        assert! (paths.contains (&((0, 2), vec![Direction::Right, Direction::Right])));
+        // Test occupied
+        assert! (!paths.iter ().any (|(l, _)| *l == (1, 0)));
+        // Test insufficient MOV
+        let paths = find_paths (game.get_grid (), &game.find_mover (0), 0);
+
+        assert_eq! (paths, vec![((0, 0), vec![])]);
+    }
+
+    #[test]
+    fn computer_decide () {
+        let game = generate_game ();
+        let mut computer = Computer;
+        let decision = computer.decide (&game, 0);
+
+        assert_eq! (decision.get_action (), Action::Attack);
+        assert_eq! (decision.get_choice (), Some (Choice::Unit (2)));
+        // Test inactive
+        let decision = Inactive.decide (&game, 0);
+
+        assert_eq! (decision, Decision::default ());
+    }
+
+    #[test]
+    fn computer_evaluate () {
+        let mut game = generate_game ();
+
+        assert_eq! (evaluate (&game, 0), 0);
+        assert_eq! (evaluate (&game, 1), 0);
+        Decision::new (Vec::new (), Action::Attack, None, Some (Choice::Unit (2))).play (&mut game);
+        assert! (evaluate (&game, 0) > 0);
+        assert! (evaluate (&game, 1) < 0);
+        assert_eq! (evaluate (&game, 0), evaluate (&game, 2));
+    }
+
+    #[test]
+    fn lookahead_decide () {
+        let game = generate_game ();
+        let mut lookahead = Lookahead::new (2);
+        let decisions = lookahead.find_decisions (&game, 0);
+
+        assert! (decisions.contains (&Decision::default ()));
+        assert! (decisions.contains (&Decision::new (Vec::new (), Action::Attack, None, Some (Choice::Unit (2)))));
+        assert! (decisions.iter ().any (|d| d.get_action () == Action::Skill));
+
+        let decision = lookahead.decide (&game, 0);
+
+        assert_ne! (decision.get_action (), Action::Wait);
+        // Test original is untouched
+        assert_eq! (game.get_number_turns (), 0);
+    }
+
+    #[test]
+    fn computer_find_approach () {
+        let scene = Scene::default ();
+        let unit_locations: Vec<Option<Location>> = scene.unit_locations_iter ().copied ().collect ();
+        let unit_locations = format! ("unit_locations:{}", ron::to_string (&unit_locations).unwrap ());
+        let unit_locations_hidden = unit_locations.replace ("Some((1,0))", "Some((0,2))");
+        let scene = ron::to_string (&scene).unwrap ();
+        assert! (scene.contains (&unit_locations));
+        let scene: Scene = ron::from_str (&scene.replace (&unit_locations, &unit_locations_hidden)).unwrap ();
+        let (sender, _) = mpsc::channel ();
+        let mut game = Game::new (scene, sender);
+
+        game.init ().unwrap ();
+        assert! (!game.is_visible (0, &(0, 2)));
+
+        // Test hidden enemies are ignored
+        let paths = find_paths (game.get_grid (), &game.find_mover (0), 10);
+        assert! (Computer.find_approach (&game, 0, &paths).is_empty ());
+        assert! (Computer.find_retreat (&game, 0, &paths).is_empty ());
+        let decisions = Lookahead::default ().find_decisions (&game, 0);
+        assert! (!decisions.iter ().any (|d| d.get_choice () == Some (Choice::Location ((0, 2)))));
+    }
+}
@@ -0,0 +1,78 @@
+use crate::map::Direction;
+use crate::system::{Action, Command, Prompt};
+use sdl2::keyboard::Keycode;
+
+fn get_direction (input: Keycode) -> Option<Direction> {
+    match input {
+        Keycode::W => Some (Direction::Up),
+        Keycode::A => Some (Direction::Left),
+        Keycode::S => Some (Direction::Down),
+        Keycode::D => Some (Direction::Right),
+        _ => None,
+    }
+}
+
+pub fn get_command (input: Keycode, prompt: Prompt) -> Option<Command> {
+    match prompt {
+        Prompt::Action => match input {
+            Keycode::Q => Some (Command::Action (Action::Move)),
+            Keycode::W => Some (Command::Action (Action::Weapon)),
+            Keycode::A => Some (Command::Action (Action::Attack)),
+            Keycode::S => Some (Command::Action (Action::Skill)),
+            Keycode::D => Some (Command::Action (Action::Magic)),
+            Keycode::Z => Some (Command::Action (Action::Wait)),
+            Keycode::X => Some (Command::Cancel),
+            _ => None,
+        }
+        Prompt::Index => match input {
+            Keycode::A => Some (Command::Previous),
+            Keycode::D => Some (Command::Next),
+            Keycode::Z => Some (Command::Confirm),
+            Keycode::X => Some (Command::Cancel),
+            _ => None,
+        }
+        Prompt::Direction => match input {
+            Keycode::X => Some (Command::Cancel),
+            _ => get_direction (input).map (Command::Direction),
+        }
+        Prompt::Movement => match input {
+            Keycode::Z => Some (Command::Confirm),
+            Keycode::X => Some (Command::Cancel),
+            _ => get_direction (input).map (Command::Direction),
+        }
+        Prompt::Confirmation => match input {
+            Keycode::Z => Some (Command::Confirm),
+            Keycode::X => Some (Command::Cancel),
+            _ => None,
+        }
+    }
+}
+
+pub fn get_prompt (prompt: Prompt) -> &'static str {
+    match prompt {
+        // "move (q), switch weapon (w), attack (a), skill (s), magic (d), wait (z), quit (x)"
+        Prompt::Action => "move (q), switch weapon (w), attack (a), skill (s), magic (d), wait (z)",
+        Prompt::Index => "previous (a), next (d), confirm (z), cancel (x)",
+        Prompt::Direction => "up (w), left (a), down (s), right (d), cancel (x)",
+        Prompt::Movement => "up (w), left (a), down (s), right (d), confirm (z), cancel (x)",
+        Prompt::Confirmation => "confirm (z), cancel (x)",
+    }
+}
+
+#[cfg (test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn human_get_command () {
+        assert_eq! (get_command (Keycode::A, Prompt::Action), Some (Command::Action (Action::Attack)));
+        assert_eq! (get_command (Keycode::A, Prompt::Index), Some (Command::Previous));
+        assert_eq! (get_command (Keycode::A, Prompt::Direction), Some (Command::Direction (Direction::Left)));
+        assert_eq! (get_command (Keycode::A, Prompt::Movement), Some (Command::Direction (Direction::Left)));
+        assert_eq! (get_command (Keycode::A, Prompt::Confirmation), None);
+        assert_eq! (get_command (Keycode::Z, Prompt::Action), Some (Command::Action (Action::Wait)));
+        assert_eq! (get_command (Keycode::Z, Prompt::Direction), None);
+        assert_eq! (get_command (Keycode::Z, Prompt::Movement), Some (Command::Confirm));
+        assert_eq! (get_command (Keycode::X, Prompt::Confirmation), Some (Command::Cancel));
+    }
+}
@@ -0,0 +1,23 @@
+use crate::common::ID;
+use crate::system::{Decision, Game};
+
+mod computer;
+pub use self::computer::*;
+#[cfg (feature = "gui")]
+mod human;
+#[cfg (feature = "gui")]
+pub use self::human::*;
+
+pub trait Controller {
+    /*
+     * Decides how unit_id plays out its turn
+     *
+     * game: &Game = current game
+     * unit_id: ID = unit whose turn it is
+     *
+     * Pre: unit_id is the active unit in game
+     * Post: None
+     * Return: Decision = movements, action, and target for unit_id
+     */
+    fn decide (&mut self, game: &Game, unit_id: ID) -> Decision;
+}
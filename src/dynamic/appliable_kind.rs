@@ -1,10 +1,12 @@
 use super::{Appliable, Attribute, Effect, Modifier};
 use crate::common::{ID, Scene};
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
 #[derive (Debug)]
 #[derive (Clone, Copy)]
 #[derive (PartialEq)]
+#[derive (Deserialize, Serialize)]
 pub enum AppliableKind {
     Modifier (ID), // modifier
     Effect (ID), // effect
@@ -15,7 +17,7 @@ impl AppliableKind {
     pub fn modifier (&self, scene: Rc<Scene>) -> Modifier {
         match self {
             AppliableKind::Modifier (m) => {
-                *scene.get_modifier (m)
+                scene.get_modifier (m).clone ()
             }
             AppliableKind::Effect ( .. ) => unimplemented! (),
             AppliableKind::Attribute ( .. ) => unimplemented! (),
@@ -36,7 +38,7 @@ impl AppliableKind {
         match self {
             AppliableKind::Modifier ( .. ) => unimplemented! (),
             AppliableKind::Effect (e) => {
-                *scene.get_effect (e)
+                scene.get_effect (e).clone ()
             }
             AppliableKind::Attribute ( .. ) => unimplemented! (),
         }
@@ -1,14 +1,17 @@
 use super::{Adjustment, Appliable, Applier, Effect, Modifier, AppliableKind, Trigger};
 use crate::common::{Capacity, DURATION_PERMANENT, ID, Scene, Target, Timed};
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
 #[derive (Debug)]
 #[derive (Clone, Copy)]
+#[derive (Deserialize, Serialize)]
 pub struct Attribute {
     id: ID,
     kind: AppliableKind,
     trigger: Trigger,
     duration: Capacity,
+    #[serde (default)]
     applier_id: Option<ID>,
 }
 
@@ -85,6 +88,7 @@ impl Applier for Attribute {
             Trigger::OnHit => Target::Enemy,
             Trigger::OnAttack => Target::Enemy,
             Trigger::OnOccupy => Target::Map,
+            Trigger::OnDeath => Target::Allies,
         }
     }
 }
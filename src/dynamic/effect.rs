@@ -1,16 +1,20 @@
 use super::{Adjustment, Appliable, Attribute, Modifier, AppliableKind};
 use crate::common::ID;
+use serde::{Deserialize, Serialize};
 
 #[derive (Debug)]
-#[derive (Clone, Copy)]
+#[derive (Clone)]
+#[derive (Deserialize, Serialize)]
 pub struct Effect {
     id: ID,
-    adjustments: &'static [Adjustment],
+    adjustments: Vec<Adjustment>,
     is_flat: bool,
 }
 
 impl Effect {
-    pub const fn new (id: ID, adjustments: &'static [Adjustment], is_flat: bool) -> Self {
+    pub fn new (id: ID, adjustments: &[Adjustment], is_flat: bool) -> Self {
+        let adjustments: Vec<Adjustment> = adjustments.to_vec ();
+
         Self { id, adjustments, is_flat }
     }
 
@@ -25,7 +29,7 @@ impl Appliable for Effect {
     }
 
     fn effect (&self) -> Effect {
-        *self
+        self.clone ()
     }
 
     fn attribute (&self) -> Attribute {
@@ -37,7 +41,7 @@ impl Appliable for Effect {
     }
 
     fn get_adjustments (&self) -> &[Adjustment] {
-        self.adjustments
+        &self.adjustments
     }
 
     fn can_stack_or_is_flat (&self) -> bool {
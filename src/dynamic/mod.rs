@@ -1,5 +1,6 @@
 use crate::character::UnitStatistic;
 use crate::common::{ID, Scene, Target};
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
 mod appliable_kind;
@@ -126,6 +127,7 @@ pub trait Dynamic {
 
 #[derive (Debug)]
 #[derive (Clone, Copy)]
+#[derive (Deserialize, Serialize)]
 pub enum StatisticKind {
     Unit (UnitStatistic),
     Tile (bool), // false = set to constant, true = flat change
@@ -134,9 +136,11 @@ pub enum StatisticKind {
 #[derive (Debug)]
 #[derive (Clone, Copy)]
 #[derive (Eq, Hash, PartialEq)]
+#[derive (Deserialize, Serialize)]
 pub enum Trigger {
     OnHit, // units only
     OnAttack, // units (weapons) only
     OnOccupy, // tiles only
+    OnDeath, // units only
     // None, // units and tiles
 }
@@ -1,22 +1,25 @@
-use super::{Adjustment, Appliable, Attribute, Effect, AppliableKind};
+use super::{Adjustment, Appliable, Attribute, Effect, AppliableKind, StatisticKind};
+use crate::character::MULTIPLIER_BASE;
 use crate::common::{Capacity, Timed, DURATION_PERMANENT, ID, ID_UNINITIALISED};
-
-const ADJUSTMENTS_EMPTY: &[Adjustment] = &[];
+use serde::{Deserialize, Serialize};
 
 #[derive (Debug)]
-#[derive (Clone, Copy)]
+#[derive (Clone)]
+#[derive (Deserialize, Serialize)]
 pub struct Modifier {
     id: ID,
-    adjustments: &'static [Adjustment],
+    adjustments: Vec<Adjustment>,
     duration: Capacity,
     can_stack: bool,
     is_every_turn: bool,
     next_id: Option<ID>,
+    #[serde (default)]
     applier_id: Option<ID>,
 }
 
 impl Modifier {
-    pub const fn new (id: ID, adjustments: &'static [Adjustment], duration: u16, can_stack: bool, is_every_turn: bool, next_id: Option<ID>) -> Self {
+    pub fn new (id: ID, adjustments: &[Adjustment], duration: u16, can_stack: bool, is_every_turn: bool, next_id: Option<ID>) -> Self {
+        let adjustments: Vec<Adjustment> = adjustments.to_vec ();
         let duration: Capacity = if duration < DURATION_PERMANENT {
             Capacity::Quantity (duration, duration)
         } else {
@@ -42,11 +45,22 @@ impl Modifier {
     pub fn set_is_every_turn (&mut self, is_every_turn: bool) {
         self.is_every_turn = is_every_turn;
     }
+
+    // Tile adjustments are movement costs rather than strengths, so only unit adjustments are scaled
+    pub fn scale (&mut self, multiplier: u16) {
+        for (statistic, change, _) in self.adjustments.iter_mut () {
+            if let StatisticKind::Tile ( .. ) = statistic {
+                continue
+            }
+
+            *change = u32::min ((*change as u32) * (multiplier as u32) / (MULTIPLIER_BASE as u32), u16::MAX as u32) as u16;
+        }
+    }
 }
 
 impl Appliable for Modifier {
     fn modifier (&self) -> Modifier {
-        *self
+        self.clone ()
     }
 
     fn effect (&self) -> Effect {
@@ -62,7 +76,7 @@ impl Appliable for Modifier {
     }
 
     fn get_adjustments (&self) -> &[Adjustment] {
-        self.adjustments
+        &self.adjustments
     }
 
     fn can_stack_or_is_flat (&self) -> bool {
@@ -113,7 +127,7 @@ impl PartialEq for Modifier {
 impl Default for Modifier {
     fn default () -> Self {
         let id: ID = ID_UNINITIALISED;
-        let adjustments: &'static [Adjustment] = ADJUSTMENTS_EMPTY;
+        let adjustments: Vec<Adjustment> = Vec::new ();
         let duration: Capacity = Capacity::Constant (1, 0, 0);
         let can_stack: bool = false;
         let is_every_turn: bool = false;
@@ -131,8 +145,8 @@ mod tests {
 
     fn generate_modifiers () -> (Modifier, Modifier) {
         let scene = generate_scene ();
-        let modifier_0 = *scene.get_modifier (&0);
-        let modifier_1 = *scene.get_modifier (&1);
+        let modifier_0 = scene.get_modifier (&0).clone ();
+        let modifier_1 = scene.get_modifier (&1).clone ();
 
         (modifier_0, modifier_1)
     }
@@ -154,4 +168,19 @@ mod tests {
         assert! (modifier_1.decrement_duration ());
         assert_eq! (modifier_1.get_duration (), DURATION_PERMANENT);
     }
+
+    #[test]
+    fn modifier_scale () {
+        let (mut modifier_0, _) = generate_modifiers ();
+        let scene = generate_scene ();
+        let mut modifier_4 = scene.get_modifier (&4).clone ();
+
+        // Test unit adjustments
+        modifier_4.scale (150);
+        assert_eq! (modifier_4.get_adjustments ()[0].1, 15);
+        assert_eq! (modifier_4.get_adjustments ()[1].1, 15);
+        // Test tile adjustments
+        modifier_0.scale (150);
+        assert_eq! (modifier_0.get_adjustments ()[0].1, 1);
+    }
 }
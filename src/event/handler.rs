@@ -49,12 +49,18 @@ impl Handler {
 
     pub fn notify (&self, message: Message) -> Vec<Response> {
         match self.message_observers.get_first (&message.discriminant ()) {
-            Some (c) => c.iter ().filter_map (|o: &ID|
-                self.id_observers.get (o)
-                        .unwrap_or_else (|| panic! ("Observer not found for ID {:?}", o))
-                        .borrow ()
-                        .respond (message)
-            ).collect::<Vec<Response>> (),
+            Some (c) => {
+                let mut observer_ids: Vec<&ID> = c.iter ().collect ();
+
+                // Observers respond in registration order
+                observer_ids.sort_unstable ();
+                observer_ids.into_iter ().filter_map (|o: &ID|
+                    self.id_observers.get (o)
+                            .unwrap_or_else (|| panic! ("Observer not found for ID {:?}", o))
+                            .borrow ()
+                            .respond (message)
+                ).collect::<Vec<Response>> ()
+            }
             None => Vec::new (),
         }
     }
@@ -108,6 +114,7 @@ mod tests {
 
                     Some (Response::TestSubtract (data))
                 }
+                _ => None,
             }
         }
 
@@ -1,10 +1,16 @@
 use crate::common::ID;
+use crate::map::Location;
 
 #[derive (Debug)]
 #[derive (Clone, Copy)]
 pub enum Message {
     TestAdd,
     TestSubtract,
+    UnitDied (ID), // unit
+    UnitMoved (ID, Location), // unit, destination
+    CityCaptured (ID, ID), // city, faction
+    TurnReached (usize), // turns
+    FactionEliminated (ID), // faction
 }
 
 impl Message {
@@ -12,6 +18,11 @@ impl Message {
         match self {
             Message::TestAdd => 0,
             Message::TestSubtract => 1,
+            Message::UnitDied ( .. ) => 2,
+            Message::UnitMoved ( .. ) => 3,
+            Message::CityCaptured ( .. ) => 4,
+            Message::TurnReached ( .. ) => 5,
+            Message::FactionEliminated ( .. ) => 6,
         }
     }
 }
@@ -20,6 +31,13 @@ impl Message {
 pub enum Response {
     TestAdd (u8),
     TestSubtract (u8),
+    Script (ID), // script
+    ApplyDeath (ID), // unit
+    RerouteFollowers (ID), // leader
+    RemoveUnit (ID), // unit
+    RemoveTurn (ID), // unit
+    Log (String),
+    CheckElimination (ID), // unit
 }
 
 impl Response {
@@ -27,6 +45,13 @@ impl Response {
         match self {
             Response::TestAdd ( .. ) => 0,
             Response::TestSubtract ( .. ) => 1,
+            Response::Script ( .. ) => 2,
+            Response::ApplyDeath ( .. ) => 3,
+            Response::RerouteFollowers ( .. ) => 4,
+            Response::RemoveUnit ( .. ) => 5,
+            Response::RemoveTurn ( .. ) => 6,
+            Response::Log ( .. ) => 7,
+            Response::CheckElimination ( .. ) => 8,
         }
     }
 }
@@ -5,7 +5,16 @@ mod handler;
 pub use self::handler::*;
 mod message_response;
 pub use self::message_response::*;
+mod script;
+pub use self::script::*;
+mod subscriber;
+pub use self::subscriber::*;
 
+/*
+ * Game owns Handler, Handler owns every Observer, and no Observer owns any game state
+ * Observers only translate messages into responses, which Game applies to itself in registration order
+ * This keeps Game out of RefCells at the cost of routing every reaction back through Game
+ */
 pub trait Observer: Debug {
     /*
      * Responds to message
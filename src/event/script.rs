@@ -0,0 +1,140 @@
+use super::{Message, Observer, Response};
+use crate::common::{ID, ID_UNINITIALISED};
+use crate::map::Location;
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+
+#[derive (Debug)]
+#[derive (Clone, Copy)]
+#[derive (PartialEq)]
+#[derive (Deserialize, Serialize)]
+pub enum Condition {
+    UnitDied (Option<ID>), // unit, None -> any unit
+    UnitMoved (Option<ID>, Location), // unit (None -> any unit), destination
+    CityCaptured (ID, Option<ID>), // city, faction (None -> any faction)
+    TurnReached (usize), // turns
+    FactionEliminated (ID), // faction
+}
+
+impl Condition {
+    pub fn is_met (&self, message: Message) -> bool {
+        match (*self, message) {
+            (Condition::UnitDied (u), Message::UnitDied (unit_id)) => u.is_none_or (|u: ID| u == unit_id),
+            (Condition::UnitMoved (u, l), Message::UnitMoved (unit_id, location)) => u.is_none_or (|u: ID| u == unit_id) && l == location,
+            (Condition::CityCaptured (c, f), Message::CityCaptured (city_id, faction_id)) => c == city_id && f.is_none_or (|f: ID| f == faction_id),
+            (Condition::TurnReached (t), Message::TurnReached (turns)) => t == turns,
+            (Condition::FactionEliminated (f), Message::FactionEliminated (faction_id)) => f == faction_id,
+            _ => false,
+        }
+    }
+
+    pub const fn discriminant (&self) -> ID {
+        match self {
+            Condition::UnitDied ( .. ) => Message::UnitDied (0).discriminant (),
+            Condition::UnitMoved ( .. ) => Message::UnitMoved (0, (0, 0)).discriminant (),
+            Condition::CityCaptured ( .. ) => Message::CityCaptured (0, 0).discriminant (),
+            Condition::TurnReached ( .. ) => Message::TurnReached (0).discriminant (),
+            Condition::FactionEliminated ( .. ) => Message::FactionEliminated (0).discriminant (),
+        }
+    }
+}
+
+#[derive (Debug)]
+#[derive (Clone)]
+#[derive (PartialEq)]
+#[derive (Deserialize, Serialize)]
+pub enum Consequence {
+    Spawn (ID, Location), // unit, location (nearest placeable)
+    Ally (ID, ID, bool), // faction, faction, is_ally
+    Modify (ID, Location, u8), // modifier, location, radius
+    Dialogue (String),
+}
+
+#[derive (Debug)]
+#[derive (Clone)]
+#[derive (Deserialize, Serialize)]
+pub struct Script {
+    condition: Condition,
+    consequences: Vec<Consequence>,
+    is_repeatable: bool,
+}
+
+impl Script {
+    pub fn new (condition: Condition, consequences: Vec<Consequence>, is_repeatable: bool) -> Self {
+        Self { condition, consequences, is_repeatable }
+    }
+
+    pub fn get_condition (&self) -> Condition {
+        self.condition
+    }
+
+    pub fn get_consequences (&self) -> &[Consequence] {
+        &self.consequences
+    }
+
+    pub fn is_repeatable (&self) -> bool {
+        self.is_repeatable
+    }
+}
+
+// Observers only report which script to run, so they hold no game state
+#[derive (Debug)]
+pub struct ScriptObserver {
+    script_id: ID,
+    condition: Condition,
+    observer_id: Cell<ID>,
+}
+
+impl ScriptObserver {
+    pub fn new (script_id: ID, condition: Condition) -> Self {
+        let observer_id: Cell<ID> = Cell::new (ID_UNINITIALISED);
+
+        Self { script_id, condition, observer_id }
+    }
+}
+
+impl Observer for ScriptObserver {
+    fn respond (&self, message: Message) -> Option<Response> {
+        self.condition.is_met (message).then_some (Response::Script (self.script_id))
+    }
+
+    fn set_observer_id (&self, observer_id: ID) -> bool {
+        if self.observer_id.get () < ID_UNINITIALISED {
+            false
+        } else {
+            self.observer_id.replace (observer_id);
+
+            true
+        }
+    }
+}
+
+#[cfg (test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn condition_is_met () {
+        // Test any unit
+        assert! (Condition::UnitDied (None).is_met (Message::UnitDied (3)));
+        assert! (Condition::UnitDied (Some (3)).is_met (Message::UnitDied (3)));
+        assert! (!Condition::UnitDied (Some (2)).is_met (Message::UnitDied (3)));
+        // Test location
+        assert! (Condition::UnitMoved (None, (1, 1)).is_met (Message::UnitMoved (0, (1, 1))));
+        assert! (!Condition::UnitMoved (None, (1, 1)).is_met (Message::UnitMoved (0, (1, 0))));
+        assert! (Condition::CityCaptured (1, None).is_met (Message::CityCaptured (1, 2)));
+        assert! (!Condition::CityCaptured (1, Some (0)).is_met (Message::CityCaptured (1, 2)));
+        assert! (Condition::TurnReached (4).is_met (Message::TurnReached (4)));
+        // Test mismatched message
+        assert! (!Condition::FactionEliminated (0).is_met (Message::UnitDied (0)));
+    }
+
+    #[test]
+    fn script_observer_respond () {
+        let observer = ScriptObserver::new (2, Condition::TurnReached (4));
+
+        assert_eq! (observer.condition.discriminant (), Message::TurnReached (4).discriminant ());
+        assert! (matches! (observer.respond (Message::TurnReached (4)), Some (Response::Script (2))));
+        assert! (observer.respond (Message::TurnReached (5)).is_none ());
+    }
+}
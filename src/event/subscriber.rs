@@ -0,0 +1,79 @@
+use super::{Message, Observer, Response};
+use crate::common::{ID, ID_UNINITIALISED};
+use std::cell::Cell;
+
+/*
+ * Subsystems that each own one step of a unit's death
+ * Game registers them in the order their steps must happen
+ */
+#[derive (Debug)]
+#[derive (Clone, Copy)]
+#[derive (PartialEq)]
+pub enum Subsystem {
+    Death, // on-death attributes
+    Leadership, // followers of a fallen leader
+    Occupancy, // grid and visibility
+    TurnOrder,
+    Logger,
+    Victory, // faction elimination
+}
+
+// Subscribers only report which step to take, so they hold no game state
+#[derive (Debug)]
+pub struct Subscriber {
+    subsystem: Subsystem,
+    observer_id: Cell<ID>,
+}
+
+impl Subscriber {
+    pub fn new (subsystem: Subsystem) -> Self {
+        let observer_id: Cell<ID> = Cell::new (ID_UNINITIALISED);
+
+        Self { subsystem, observer_id }
+    }
+}
+
+impl Observer for Subscriber {
+    fn respond (&self, message: Message) -> Option<Response> {
+        if let Message::UnitDied (unit_id) = message {
+            let response: Response = match self.subsystem {
+                Subsystem::Death => Response::ApplyDeath (unit_id),
+                Subsystem::Leadership => Response::RerouteFollowers (unit_id),
+                Subsystem::Occupancy => Response::RemoveUnit (unit_id),
+                Subsystem::TurnOrder => Response::RemoveTurn (unit_id),
+                Subsystem::Logger => Response::Log (format! ("{} died", unit_id)),
+                Subsystem::Victory => Response::CheckElimination (unit_id),
+            };
+
+            Some (response)
+        } else {
+            None
+        }
+    }
+
+    fn set_observer_id (&self, observer_id: ID) -> bool {
+        if self.observer_id.get () < ID_UNINITIALISED {
+            false
+        } else {
+            self.observer_id.replace (observer_id);
+
+            true
+        }
+    }
+}
+
+#[cfg (test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subscriber_respond () {
+        let subscriber = Subscriber::new (Subsystem::Occupancy);
+
+        assert! (matches! (subscriber.respond (Message::UnitDied (1)), Some (Response::RemoveUnit (1))));
+        assert! (subscriber.respond (Message::TurnReached (1)).is_none ());
+        assert! (matches! (Subscriber::new (Subsystem::Logger).respond (Message::UnitDied (1)), Some (Response::Log (m)) if m == "1 died"));
+        assert! (subscriber.set_observer_id (0));
+        assert! (!subscriber.set_observer_id (1));
+    }
+}
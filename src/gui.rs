@@ -0,0 +1,197 @@
+use crate::common::{ID, Scene, SceneError};
+use crate::controller::{self, Controller};
+use crate::system::{Command, Decision, Game, Logger, Prompt, Renderer, Replay, Save};
+use sdl2::event::Event;
+use sdl2::image::{self as sdl2_image, Sdl2ImageContext};
+use sdl2::keyboard::Keycode;
+use sdl2::mixer::{self as sdl2_mixer, Sdl2MixerContext};
+use sdl2::pixels::Color;
+use sdl2::render::{Canvas, TextureCreator};
+use sdl2::ttf::{self as sdl2_ttf, Sdl2TtfContext};
+use sdl2::video::{Window, WindowContext};
+use sdl2::{EventPump, Sdl, VideoSubsystem};
+use std::error::Error;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const FRAMES_PER_SECOND: u128 = 30;
+const NANOS_PER_FRAME: u128 = 1_000_000_000 / FRAMES_PER_SECOND;
+const SAVE_PATH: &str = "save.ron";
+const REPLAY_PATH: &str = "replay.ron";
+
+pub struct Danubia {
+    image: Sdl2ImageContext,
+    mixer: Sdl2MixerContext,
+    ttf: Sdl2TtfContext,
+    canvas: Canvas<Window>,
+    event_pump: EventPump,
+    renderer: Renderer,
+    game: Game,
+    controllers: Vec<Option<Box<dyn Controller>>>, // None -> human, Some (controller) -> computer
+    replay: Option<Replay>,
+}
+
+impl Danubia {
+    pub fn new (scene: Scene, controllers: Vec<Option<Box<dyn Controller>>>) -> Result<Self, Box<dyn Error>> {
+        let number_factions: usize = scene.faction_builders_iter ().count ();
+
+        if controllers.len () != number_factions {
+            return Err (Box::from (format! ("Expected {} controllers, found {}", number_factions, controllers.len ())))
+        }
+
+
+        // SDL2 boilerplate
+        let sdl: Sdl = sdl2::init ()?;
+        let image: Sdl2ImageContext = sdl2_image::init (sdl2_image::InitFlag::PNG)?;
+        let mixer: Sdl2MixerContext = sdl2_mixer::init (sdl2_mixer::InitFlag::all ())?;
+        let ttf: Sdl2TtfContext = sdl2_ttf::init ()?;
+        let video: VideoSubsystem = sdl.video ()?;
+        let window: Window = video.window ("Danubia", 640, 480)
+                .position_centered ()
+                .build ()?;
+        let mut canvas: Canvas<Window> = window.into_canvas ().build ()?;
+        let texture_creator: TextureCreator<WindowContext> = canvas.texture_creator ();
+        let event_pump: EventPump = sdl.event_pump ()?;
+
+        canvas.set_draw_color (Color::RGB (255, 255, 255));
+        canvas.clear ();
+        canvas.present ();
+
+        let (sender, receiver): (Sender<String>, Receiver<String>) = mpsc::channel ();
+
+        thread::spawn (move || Logger::new ("log.txt", receiver).run ());
+
+        let renderer: Renderer = Renderer::new (&texture_creator, &scene)?;
+        let replay: Option<Replay> = Some (Replay::new (scene.calculate_hash ()));
+        let mut game: Game = Game::new (scene, sender);
+
+        game.init ()?;
+
+        Ok (Danubia { image, mixer, ttf, canvas, event_pump, renderer, game, controllers, replay })
+    }
+
+    pub fn load (&mut self, save: &Save) -> Result<(), Box<dyn Error>> {
+        let texture_creator: TextureCreator<WindowContext> = self.canvas.texture_creator ();
+
+        self.game.load (save).map_err (|errors: Vec<SceneError>| {
+            let errors: Vec<String> = errors.iter ().map (|e: &SceneError| e.to_string ()).collect ();
+
+            errors.join ("\n")
+        })?;
+        self.renderer = Renderer::new (&texture_creator, save.get_scene ())?;
+        // Replays start from a scene, so they cannot continue from a save
+        self.replay = None;
+
+        Ok (())
+    }
+
+    pub fn run (&mut self) -> Result<(), Box<dyn Error>> {
+        let mut is_display_turn: bool = true;
+        let mut is_display_prompt: bool = true;
+
+        'running: loop {
+            let frame_start: Instant = Instant::now ();
+            let mut input: Option<Command> = None;
+            let mut save: Option<Save> = None;
+
+            if is_display_turn {
+                self.game.display_turn ();
+                is_display_turn = false;
+            }
+
+            if !self.game.is_over () && self.game.get_prompt () == Prompt::Action {
+                let unit_id: ID = self.game.get_unit_id_active ();
+                let faction_id: ID = self.game.get_unit (&unit_id).get_faction_id ();
+
+                if let Some (controller) = &mut self.controllers[faction_id] {
+                    let decision: Decision = controller.decide (&self.game, unit_id);
+                    let commands: Vec<Command> = decision.play (&mut self.game);
+
+                    if let Some (replay) = &mut self.replay {
+                        for command in commands {
+                            replay.record (command);
+                        }
+                    }
+
+                    is_display_turn = true;
+                    is_display_prompt = true;
+                }
+            }
+
+            if is_display_prompt {
+                println! ("{}", controller::get_prompt (self.game.get_prompt ()));
+                is_display_prompt = false;
+            }
+
+            for event in self.event_pump.poll_iter () {
+                match event {
+                    Event::Quit { .. } => break 'running,
+                    // TODO: KeyDown or KeyUp?
+                    Event::KeyDown { keycode: Some (keycode), repeat: false, .. } => {
+                        match keycode {
+                            Keycode::Escape => break 'running,
+                            Keycode::F5 => match self.game.save ().store (SAVE_PATH) {
+                                Ok (()) => println! ("Saved to {}", SAVE_PATH),
+                                Err (e) => println! ("{}", e),
+                            }
+                            Keycode::F9 => match Save::load (SAVE_PATH) {
+                                Ok (s) => save = Some (s),
+                                Err (e) => println! ("{}", e),
+                            }
+                            _ => {
+                                input = controller::get_command (keycode, self.game.get_prompt ());
+                                println! ("{}", keycode.name ());
+
+                                if input.is_none () {
+                                    println! ("Invalid input");
+                                }
+                            }
+                        }
+                    }
+                    _ => (),
+                }
+            }
+
+            if let Some (save) = save {
+                self.load (&save)?;
+                is_display_turn = true;
+                is_display_prompt = true;
+                println! ("Loaded from {}", SAVE_PATH);
+            }
+
+            if let Some (input) = input {
+                is_display_prompt = true;
+
+                if let Some (replay) = &mut self.replay {
+                    replay.record (input);
+                }
+
+                if self.game.update (input).is_turn_over () {
+                    is_display_turn = true;
+                }
+            }
+
+            // Humans see through the first human faction's eyes
+            let faction_id_viewer: Option<ID> = self.controllers.iter ().position (|c: &Option<Box<dyn Controller>>| c.is_none ());
+
+            self.canvas.set_draw_color (Color::RGB (255, 255, 255));
+            self.canvas.clear ();
+            // TODO: Render context
+            self.renderer.render (&mut self.canvas, &self.game.get_render_context (faction_id_viewer));
+            self.canvas.present ();
+
+            let frame_elapsed: u128 = frame_start.elapsed ().as_nanos ();
+
+            if let Some (frame_sleep) = NANOS_PER_FRAME.checked_sub (frame_elapsed) {
+                thread::sleep (Duration::new (0, frame_sleep as u32));
+            }
+        }
+
+        if let Some (replay) = &self.replay {
+            replay.store (REPLAY_PATH)?;
+        }
+
+        Ok (())
+    }
+}
@@ -1,8 +1,23 @@
 use danubia::Danubia;
+use danubia::common::Scene;
+use danubia::controller::{Computer, Controller};
+use std::env;
 use std::error::Error;
 
 fn main () -> Result<(), Box<dyn Error>> {
-    let mut danubia = Danubia::new ()?;
+    let scene: Scene = match env::args ().nth (1) {
+        Some (path) => Scene::load (path)?,
+        None => Scene::default (),
+    };
+    // The first faction is the player's
+    let controllers: Vec<Option<Box<dyn Controller>>> = scene.faction_builders_iter ().enumerate ().map (|(i, _)|
+        if i > 0 {
+            Some (Box::new (Computer) as Box<dyn Controller>)
+        } else {
+            None
+        }
+    ).collect ();
+    let mut danubia = Danubia::new (scene, controllers)?;
 
     danubia.run ()
 }
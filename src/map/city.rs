@@ -1,4 +1,5 @@
 use crate::common::ID;
+use serde::{Deserialize, Serialize};
 
 pub const WORKERS_FACTORY: f32 = 4.0;
 pub const WORKERS_FARM: f32 = 1.5;
@@ -7,6 +8,8 @@ const RECOVER_EQUIPMENT_MODIFIER: f32 = 2.5;
 const MODIFIER_MINIMUM: f32 = 0.67;
 
 #[derive (Debug)]
+#[derive (Clone)]
+#[derive (Deserialize, Serialize)]
 pub struct City {
     population: u16, // (thousands)
     factories: u16,
@@ -1,8 +1,11 @@
-use super::{City, COST_IMPASSABLE, Search, Tile, TileBuilder};
+use super::{Area, City, COST_IMPASSABLE, COST_MINIMUM, Mover, Reach, Reachability, Search, ThreatMap, Tile, TileBuilder, ZoneOfControl, ZoneRule};
+use crate::character::MULTIPLIER_BASE;
 use crate::collections::{InnerJoinMap, OuterJoinMap};
 use crate::common::{ID, ID_UNINITIALISED, Scene};
 use crate::dynamic::{Appliable, Applier, Dynamic, Modifier, AppliableKind};
-use std::collections::{HashSet, VecDeque};
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet, VecDeque};
 use std::fmt::{self, Display, Formatter};
 use std::rc::Rc;
 
@@ -11,10 +14,10 @@ type Rectangle<T> = Vec<Vec<T>>;
 type Row<T> = Vec<T>;
 type Adjacency = [u8; Direction::Length as usize]; // cost, climb
 
-const DIRECTIONS: [Direction; Direction::Length as usize] = [Direction::Up, Direction::Right, Direction::Left, Direction::Down];
+pub const DIRECTIONS: [Direction; Direction::Length as usize] = [Direction::Up, Direction::Right, Direction::Left, Direction::Down];
 const FACTION_UNCONTROLLED: ID = ID_UNINITIALISED;
 
-const fn switch_direction (direction: Direction) -> Direction {
+pub const fn switch_direction (direction: Direction) -> Direction {
     DIRECTIONS[(Direction::Length as usize) - (direction as usize) - 1]
 }
 
@@ -34,6 +37,8 @@ fn is_in_bounds<T> (grid: &Rectangle<T>, location: &Location) -> bool {
 
 #[derive (Debug)]
 #[derive (Clone, Copy)]
+#[derive (PartialEq)]
+#[derive (Deserialize, Serialize)]
 pub enum Direction {
     Up,
     Right,
@@ -43,7 +48,10 @@ pub enum Direction {
 }
 
 #[derive (Debug)]
+#[derive (Clone)]
+#[derive (Deserialize, Serialize)]
 pub struct Grid {
+    #[serde (skip, default = "Scene::placeholder")]
     scene: Rc<Scene>,
     tiles: Rectangle<Tile>,
     adjacencies: Rectangle<Adjacency>,
@@ -54,7 +62,7 @@ pub struct Grid {
 
 impl Grid {
     pub fn new (scene: Rc<Scene>) -> Self {
-        let tile_builders: &[&[TileBuilder]] = scene.get_tile_builders ();
+        let tile_builders: &[Vec<TileBuilder>] = scene.get_tile_builders ();
         let mut tiles: Rectangle<Tile> = Rectangle::new ();
 
         for (i, row) in tile_builders.iter ().enumerate () {
@@ -159,7 +167,7 @@ impl Grid {
         !self.is_impassable (location) && !self.is_occupied (location)
     }
 
-    pub fn find_nearest_placeable (&self, location: &Location) -> Location {
+    pub fn find_nearest_placeable (&self, location: &Location) -> Option<Location> {
         assert! (is_rectangular (&self.tiles));
         assert! (is_in_bounds (&self.tiles, location));
 
@@ -171,7 +179,7 @@ impl Grid {
 
         while let Some (location) = locations.pop_front () {
             if self.is_placeable (&location) {
-                return location
+                return Some (location)
             }
 
             for direction in DIRECTIONS {
@@ -184,7 +192,7 @@ impl Grid {
             }
         }
 
-        panic! ("Placeable not found")
+        None
     }
 
     pub fn find_distance_between (&self, unit_id_first: &ID, unit_id_second: &ID) -> usize {
@@ -217,7 +225,58 @@ impl Grid {
         }
     }
 
-    pub fn try_move (&self, start: &Location, direction: Direction) -> Option<(Location, u8)> {
+    // Classes scale the cost of entering terrain, and a multiplier of 0 forbids it
+    fn scale_cost (&self, mover: Option<&Mover>, location: &Location, cost: u8) -> Option<u8> {
+        let terrain_id: ID = self.tiles[location.0][location.1].get_terrain_id ();
+        let multiplier: u16 = mover.map_or (MULTIPLIER_BASE, |m: &Mover|
+            self.scene.get_class (&m.get_class_id ()).find_multiplier (&terrain_id)
+        );
+
+        if multiplier > 0 {
+            let cost: u16 = u16::max ((cost as u16) * multiplier / MULTIPLIER_BASE, COST_MINIMUM as u16);
+
+            Some (u16::min (cost, u8::MAX as u16) as u8)
+        } else {
+            None
+        }
+    }
+
+    fn is_enemy (&self, mover: &Mover, unit_id_other: &ID) -> bool {
+        let faction_id: ID = self.get_unit_faction (&mover.get_unit_id ());
+        let faction_id_other: ID = self.get_unit_faction (unit_id_other);
+
+        faction_id != faction_id_other && !mover.is_ally (&faction_id_other)
+    }
+
+    pub fn is_controlled (&self, mover: &Mover, location: &Location) -> bool {
+        let zone_of_control: &ZoneOfControl = self.scene.get_zone_of_control ();
+
+        if let ZoneRule::Disabled = zone_of_control.get_rule () {
+            return false
+        }
+
+        if zone_of_control.is_exempt (&mover.get_class_id (), mover.get_skill_ids ()) {
+            return false
+        }
+
+        DIRECTIONS.iter ().any (|d: &Direction|
+            self.try_connect (location, *d)
+                    .and_then (|n: Location| self.get_location_unit (&n))
+                    .is_some_and (|u: &ID| self.is_enemy (mover, u))
+        )
+    }
+
+    // Units can't move any further once they enter a zone of control
+    pub fn is_stopped (&self, mover: Option<&Mover>, location: &Location) -> bool {
+        if let ZoneRule::Stop = self.scene.get_zone_of_control ().get_rule () {
+            mover.is_some_and (|m: &Mover| self.is_controlled (m, location))
+        } else {
+            false
+        }
+    }
+
+    // Without a mover, only the terrain decides the cost
+    pub fn try_move (&self, mover: Option<&Mover>, start: &Location, direction: Direction) -> Option<(Location, u8)> {
         assert! (is_rectangular (&self.adjacencies));
         assert! (is_in_bounds (&self.adjacencies, start));
 
@@ -236,6 +295,14 @@ impl Grid {
 
             if is_in_bounds (&self.adjacencies, &end) {
                 if self.is_placeable (&end) {
+                    let cost: u8 = self.scale_cost (mover, &end, cost)?;
+
+                    if let ZoneRule::Toll (toll) = self.scene.get_zone_of_control ().get_rule () {
+                        if mover.is_some_and (|m: &Mover| self.is_controlled (m, &end)) {
+                            return Some ((end, cost.saturating_add (toll)))
+                        }
+                    }
+
                     Some ((end, cost))
                 } else {
                     None
@@ -286,7 +353,8 @@ impl Grid {
         self.unit_locations.remove_first (unit_id);
     }
 
-    pub fn move_unit (&mut self, unit_id: ID, movements: &[Direction]) -> Option<(Location, ID)> {
+    pub fn move_unit (&mut self, mover: &Mover, movements: &[Direction]) -> Option<(Location, ID)> {
+        let unit_id: ID = mover.get_unit_id ();
         let mut locations: Vec<Location> = Vec::new ();
         let faction_id: ID = self.get_unit_faction (&unit_id);
         let start: Location = *self.get_unit_location (&unit_id)
@@ -297,10 +365,12 @@ impl Grid {
         // self.unit_locations.remove_first (&unit_id);
         self.unit_id_passable = Some (unit_id);
 
-        for direction in movements.iter () {
-            end = match self.try_move (&end, *direction) {
-                Some (e) => e.0,
-                None => {
+        for (i, direction) in movements.iter ().enumerate () {
+            let is_stopped: bool = i > 0 && self.is_stopped (Some (mover), &end);
+
+            end = match self.try_move (Some (mover), &end, *direction) {
+                Some (e) if !is_stopped => e.0,
+                _ => {
                     // TODO: This is probably worth a panic
                     // self.unit_locations.insert ((unit_id, end));
                     self.unit_locations.replace_first (unit_id, end);
@@ -325,40 +395,115 @@ impl Grid {
         Some ((end, terrain_id))
     }
 
-    fn find_unit_movable_helper (&self, is_visited: &mut Rectangle<bool>, location: &Location, mov: u16) {
-        is_visited[location.0][location.1] = true;
+    pub fn find_reachable_from (&self, mover: Option<&Mover>, start: &Location, mov: u16) -> Reachability {
+        assert! (is_rectangular (&self.tiles));
+        assert! (is_in_bounds (&self.tiles, start));
 
-        for direction in DIRECTIONS {
-            if let Some ((location, cost)) = self.try_move (location, direction) {
-                if let Some (mov) = mov.checked_sub (cost as u16) {
-                    self.find_unit_movable_helper (is_visited, &location, mov);
+        let mut reaches: Rectangle<Option<Reach>> = vec![vec![None; self.tiles[0].len ()]; self.tiles.len ()];
+        let mut locations: BinaryHeap<Reverse<(u16, Location)>> = BinaryHeap::new ();
+
+        reaches[start.0][start.1] = Some ((0, None));
+        locations.push (Reverse ((0, *start)));
+
+        while let Some (Reverse ((cost, location))) = locations.pop () {
+            if reaches[location.0][location.1].is_some_and (|(c, _): (u16, _)| c < cost) {
+                continue
+            }
+
+            if location != *start && self.is_stopped (mover, &location) {
+                continue
+            }
+
+            for direction in DIRECTIONS {
+                if let Some ((n, cost_move)) = self.try_move (mover, &location, direction) {
+                    let cost_n: u16 = cost + (cost_move as u16);
+
+                    if cost_n <= mov && reaches[n.0][n.1].is_none_or (|(c, _): (u16, _)| cost_n < c) {
+                        reaches[n.0][n.1] = Some ((cost_n, Some ((location, direction))));
+                        locations.push (Reverse ((cost_n, n)));
+                    }
                 }
             }
         }
+
+        Reachability::new (mov, reaches)
     }
 
-    pub fn find_unit_movable (&self, unit_id: &ID, mov: u16) -> Vec<Location> {
-        let location: Location = *self.get_unit_location (unit_id)
+    pub fn find_unit_reachable (&self, mover: &Mover, mov: u16) -> Reachability {
+        let unit_id: ID = mover.get_unit_id ();
+        let location: Location = *self.get_unit_location (&unit_id)
                 .unwrap_or_else (|| panic! ("Location not found for unit {}", unit_id));
-        let mut is_visited: Rectangle<bool> = vec![vec![false; self.tiles[0].len ()]; self.tiles.len ()];
-        let mut locations: Vec<Location> = Vec::new ();
 
-        self.find_unit_movable_helper (&mut is_visited, &location, mov);
+        self.find_reachable_from (Some (mover), &location, mov)
+    }
+
+    pub fn find_unit_movable (&self, mover: &Mover, mov: u16) -> Vec<Location> {
+        self.find_unit_reachable (mover, mov).locations_iter ().collect ()
+    }
 
-        for (i, row) in is_visited.iter ().enumerate () {
-            for (j, is_visited) in row.iter ().enumerate () {
-                if *is_visited {
-                    locations.push ((i, j))
+    pub fn find_path_from (&self, mover: Option<&Mover>, start: &Location, destination: &Location, mov: u16) -> Option<(Vec<Direction>, u16)> {
+        assert! (is_rectangular (&self.tiles));
+        assert! (is_in_bounds (&self.tiles, start));
+        assert! (is_in_bounds (&self.tiles, destination));
+
+        let mut costs: Rectangle<u16> = vec![vec![u16::MAX; self.tiles[0].len ()]; self.tiles.len ()];
+        let mut predecessors: Rectangle<Option<(Location, Direction)>> = vec![vec![None; self.tiles[0].len ()]; self.tiles.len ()];
+        let mut locations: BinaryHeap<Reverse<(u16, u16, Location)>> = BinaryHeap::new ();
+        // Every step costs at least COST_MINIMUM, so this never overestimates
+        let estimate = |location: &Location| -> u16 {
+            let distance: usize = location.0.abs_diff (destination.0) + location.1.abs_diff (destination.1);
+
+            (distance as u16).saturating_mul (COST_MINIMUM as u16)
+        };
+
+        costs[start.0][start.1] = 0;
+        locations.push (Reverse ((estimate (start), 0, *start)));
+
+        while let Some (Reverse ((_, cost, location))) = locations.pop () {
+            if location == *destination {
+                let mut movements: Vec<Direction> = Vec::new ();
+                let mut location: Location = location;
+
+                while let Some ((previous, direction)) = predecessors[location.0][location.1] {
+                    movements.push (direction);
+                    location = previous;
+                }
+
+                movements.reverse ();
+
+                return Some ((movements, cost))
+            }
+
+            if cost > costs[location.0][location.1] {
+                continue
+            }
+
+            if location != *start && self.is_stopped (mover, &location) {
+                continue
+            }
+
+            for direction in DIRECTIONS {
+                if let Some ((n, cost_move)) = self.try_move (mover, &location, direction) {
+                    let cost_n: u16 = cost + (cost_move as u16);
+
+                    if cost_n <= mov && cost_n < costs[n.0][n.1] {
+                        costs[n.0][n.1] = cost_n;
+                        predecessors[n.0][n.1] = Some ((location, direction));
+                        locations.push (Reverse ((cost_n + estimate (&n), cost_n, n)));
+                    }
                 }
             }
         }
 
-        locations
-        // is_visited.iter ().enumerate ().map (|(i, row)| {
-        //     row.iter ().enumerate ().filter_map (move |(j, is_visited)|
-        //         is_visited.then_some ((i, j))
-        //     )
-        // }).flatten ().collect ()
+        None
+    }
+
+    pub fn find_path (&self, mover: &Mover, destination: &Location, mov: u16) -> Option<(Vec<Direction>, u16)> {
+        let unit_id: ID = mover.get_unit_id ();
+        let start: Location = *self.get_unit_location (&unit_id)
+                .unwrap_or_else (|| panic! ("Location not found for unit {}", unit_id));
+
+        self.find_path_from (Some (mover), &start, destination, mov)
     }
 
     pub fn find_unit_cities (&self, unit_id: &ID) -> Vec<ID> {
@@ -485,7 +630,72 @@ impl Grid {
 
         locations.retain (|l: &Location| is_in_bounds (&self.tiles, l));
 
-        locations.into_iter ().collect::<Vec<Location>> ()
+        let mut locations: Vec<Location> = locations.into_iter ().collect ();
+
+        // Sorted for determinism
+        locations.sort_unstable ();
+
+        locations
+    }
+
+    pub fn find_locations_strike (&self, location: &Location, area: Area, range: u8) -> Vec<Location> {
+        match area {
+            Area::Single => self.find_locations (location, Search::Radial (range)),
+            // Targets are within range, and their surroundings are within the radius
+            Area::Radial (r) => self.find_locations (location, Search::Radial (range.saturating_add (r))),
+            Area::Path (w) => {
+                let mut locations: HashSet<Location> = HashSet::new ();
+
+                for direction in DIRECTIONS {
+                    locations.extend (self.find_locations (location, Search::Path (w, range, direction)));
+                }
+
+                let mut locations: Vec<Location> = locations.into_iter ().collect ();
+
+                // Sorted for determinism
+                locations.sort_unstable ();
+
+                locations
+            }
+        }
+    }
+
+    pub fn find_unit_threats (&self, threat_map: &mut ThreatMap, mover: &Mover, mov: u16, strikes: &[(Area, u8)]) {
+        let reachability: Reachability = self.find_unit_reachable (mover, mov);
+
+        for location in reachability.locations_iter () {
+            for (area, range) in strikes {
+                for l in self.find_locations_strike (&location, *area, *range) {
+                    threat_map.add_threat (&l, mover.get_unit_id ());
+                }
+            }
+        }
+    }
+
+    fn is_sight_blocked (&self, start: &Location, end: &Location) -> bool {
+        let height: u8 = u8::max (self.tiles[start.0][start.1].get_height (), self.tiles[end.0][end.1].get_height ());
+        let distance_i: isize = (end.0 as isize) - (start.0 as isize);
+        let distance_j: isize = (end.1 as isize) - (start.1 as isize);
+        let steps: isize = isize::max (distance_i.abs (), distance_j.abs ());
+
+        // Any tile on the line taller than both ends hides one from the other
+        (1 .. steps).any (|k: isize| {
+            let i: isize = (start.0 as isize) + ((distance_i * k) as f32 / (steps as f32)).round () as isize;
+            let j: isize = (start.1 as isize) + ((distance_j * k) as f32 / (steps as f32)).round () as isize;
+
+            self.tiles[i as usize][j as usize].get_height () > height
+        })
+    }
+
+    pub fn find_locations_visible (&self, location: &Location, sight: u8) -> Vec<Location> {
+        assert! (is_in_bounds (&self.tiles, location));
+
+        // Higher ground sees further
+        let sight: u8 = sight.saturating_add (self.tiles[location.0][location.1].get_height ());
+
+        self.find_locations (location, Search::Radial (sight)).into_iter ().filter (|l: &Location|
+            !self.is_sight_blocked (location, l)
+        ).collect ()
     }
 
     pub fn find_units (&self, location: &Location, search: Search) -> Vec<ID> {
@@ -528,7 +738,7 @@ impl Grid {
                     let faction_id_recruit: ID = self.get_unit_faction (&recruit_id);
 
                     if faction_id_recruit == *faction_id {
-                        let spawn: Location = self.find_nearest_placeable (&location);
+                        let spawn: Location = self.find_nearest_placeable (&location)?;
                         let terrain_id: ID = self.place_unit (recruit_id, spawn)?;
 
                         self.tiles[location.0][location.1].set_recruited (true);
@@ -658,6 +868,24 @@ impl Grid {
         self.tiles[location.0][location.1].get_modifier ()
     }
 
+    pub fn set_weather (&mut self, modifier_weather: Option<Modifier>) {
+        for row in self.tiles.iter_mut () {
+            for tile in row.iter_mut () {
+                tile.set_modifier_weather (modifier_weather.clone ());
+            }
+        }
+
+        // Every tile changes at once, so rebuilding is cheaper than updating each tile
+        self.adjacencies = Grid::build_adjacencies (&self.tiles);
+    }
+
+    pub fn is_sheltered (&self, location: &Location) -> bool {
+        assert! (is_rectangular (&self.tiles));
+        assert! (is_in_bounds (&self.tiles, location));
+
+        self.tiles[location.0][location.1].get_city_id ().is_some ()
+    }
+
     pub fn try_yield_appliable (&self, location: &Location) -> Option<Box<dyn Appliable>> {
         assert! (is_rectangular (&self.tiles));
         assert! (is_in_bounds (&self.tiles, location));
@@ -704,6 +932,20 @@ impl Grid {
     pub fn set_unit_id_passable (&mut self, unit_id_passable: Option<ID>) {
         self.unit_id_passable = unit_id_passable;
     }
+
+    pub fn get_scene (&self) -> &Rc<Scene> {
+        &self.scene
+    }
+
+    pub fn set_scene (&mut self, scene: Rc<Scene>) {
+        for row in self.tiles.iter_mut () {
+            for tile in row.iter_mut () {
+                tile.set_scene (Rc::clone (&scene));
+            }
+        }
+
+        self.scene = scene;
+    }
 }
 
 impl Display for Grid {
@@ -740,6 +982,17 @@ mod tests {
         Grid::new (Rc::clone (&scene))
     }
 
+    fn generate_mover (unit_id: ID) -> Mover {
+        let scene = generate_scene ();
+        let unit_builder = scene.get_unit_builder (&unit_id);
+        let allies = scene.get_faction_builder (&unit_builder.get_faction_id ()).get_allies ();
+        let mut skill_ids = unit_builder.get_skill_ids ().to_vec ();
+
+        skill_ids.extend (unit_builder.get_skill_passive_id ());
+
+        Mover::new (unit_id, unit_builder.get_class_id (), allies, &skill_ids)
+    }
+
     #[test]
     fn grid_get_cost () {
         let grid = generate_grid ();
@@ -814,12 +1067,18 @@ mod tests {
         let mut grid = generate_grid ();
 
         // Test empty find
-        assert_eq! (grid.find_nearest_placeable (&(0, 0)), (0, 0));
+        assert_eq! (grid.find_nearest_placeable (&(0, 0)), Some ((0, 0)));
         // Test non-empty find
         grid.place_unit (0, (0, 0));
-        assert! (grid.find_nearest_placeable (&(0, 0)) == (0, 1)
-            || grid.find_nearest_placeable (&(0, 0)) == (1, 0)
+        assert! (grid.find_nearest_placeable (&(0, 0)) == Some ((0, 1))
+            || grid.find_nearest_placeable (&(0, 0)) == Some ((1, 0))
         );
+        // Test full find
+        grid.place_unit (1, (0, 1));
+        grid.place_unit (2, (0, 2));
+        grid.place_unit (3, (1, 0));
+        grid.place_unit (4, (1, 1));
+        assert_eq! (grid.find_nearest_placeable (&(0, 0)), None);
     }
 
     #[test]
@@ -873,37 +1132,37 @@ mod tests {
         let mut grid = generate_grid ();
 
         // Test empty move
-        assert! (grid.try_move (&(0, 0), Direction::Up).is_none ());
-        assert_eq! (grid.try_move (&(0, 0), Direction::Right).unwrap (), ((0, 1), 2));
-        assert! (grid.try_move (&(0, 0), Direction::Left).is_none ());
-        assert! (grid.try_move (&(0, 0), Direction::Down).is_none ());
-        assert! (grid.try_move (&(0, 1), Direction::Up).is_none ());
-        assert_eq! (grid.try_move (&(0, 1), Direction::Right).unwrap (), ((0, 2), 2));
-        assert_eq! (grid.try_move (&(0, 1), Direction::Left).unwrap (), ((0, 0), 2));
-        assert_eq! (grid.try_move (&(0, 1), Direction::Down).unwrap (), ((1, 1), 2));
-        assert! (grid.try_move (&(0, 2), Direction::Up).is_none ());
-        assert! (grid.try_move (&(0, 2), Direction::Right).is_none ());
-        assert_eq! (grid.try_move (&(0, 2), Direction::Left).unwrap (), ((0, 1), 2));
-        assert! (grid.try_move (&(0, 2), Direction::Down).is_none ());
-
-        assert! (grid.try_move (&(1, 0), Direction::Up).is_none ());
-        assert_eq! (grid.try_move (&(1, 0), Direction::Right).unwrap (), ((1, 1), 3));
-        assert! (grid.try_move (&(1, 0), Direction::Left).is_none ());
-        assert! (grid.try_move (&(1, 0), Direction::Down).is_none ());
-        assert_eq! (grid.try_move (&(1, 1), Direction::Up).unwrap (), ((0, 1), 1));
-        assert! (grid.try_move (&(1, 1), Direction::Right).is_none ());
-        assert_eq! (grid.try_move (&(1, 1), Direction::Left).unwrap (), ((1, 0), 3));
-        assert! (grid.try_move (&(1, 1), Direction::Down).is_none ());
-        assert! (grid.try_move (&(1, 2), Direction::Up).is_none ());
-        assert! (grid.try_move (&(1, 2), Direction::Right).is_none ());
-        assert! (grid.try_move (&(1, 2), Direction::Left).is_none ());
-        assert! (grid.try_move (&(1, 2), Direction::Down).is_none ());
+        assert! (grid.try_move (None, &(0, 0), Direction::Up).is_none ());
+        assert_eq! (grid.try_move (None, &(0, 0), Direction::Right).unwrap (), ((0, 1), 2));
+        assert! (grid.try_move (None, &(0, 0), Direction::Left).is_none ());
+        assert! (grid.try_move (None, &(0, 0), Direction::Down).is_none ());
+        assert! (grid.try_move (None, &(0, 1), Direction::Up).is_none ());
+        assert_eq! (grid.try_move (None, &(0, 1), Direction::Right).unwrap (), ((0, 2), 2));
+        assert_eq! (grid.try_move (None, &(0, 1), Direction::Left).unwrap (), ((0, 0), 2));
+        assert_eq! (grid.try_move (None, &(0, 1), Direction::Down).unwrap (), ((1, 1), 2));
+        assert! (grid.try_move (None, &(0, 2), Direction::Up).is_none ());
+        assert! (grid.try_move (None, &(0, 2), Direction::Right).is_none ());
+        assert_eq! (grid.try_move (None, &(0, 2), Direction::Left).unwrap (), ((0, 1), 2));
+        assert! (grid.try_move (None, &(0, 2), Direction::Down).is_none ());
+
+        assert! (grid.try_move (None, &(1, 0), Direction::Up).is_none ());
+        assert_eq! (grid.try_move (None, &(1, 0), Direction::Right).unwrap (), ((1, 1), 3));
+        assert! (grid.try_move (None, &(1, 0), Direction::Left).is_none ());
+        assert! (grid.try_move (None, &(1, 0), Direction::Down).is_none ());
+        assert_eq! (grid.try_move (None, &(1, 1), Direction::Up).unwrap (), ((0, 1), 1));
+        assert! (grid.try_move (None, &(1, 1), Direction::Right).is_none ());
+        assert_eq! (grid.try_move (None, &(1, 1), Direction::Left).unwrap (), ((1, 0), 3));
+        assert! (grid.try_move (None, &(1, 1), Direction::Down).is_none ());
+        assert! (grid.try_move (None, &(1, 2), Direction::Up).is_none ());
+        assert! (grid.try_move (None, &(1, 2), Direction::Right).is_none ());
+        assert! (grid.try_move (None, &(1, 2), Direction::Left).is_none ());
+        assert! (grid.try_move (None, &(1, 2), Direction::Down).is_none ());
 
         // Test non-empty move
         grid.unit_locations.insert ((0, (0, 1)));
-        assert! (grid.try_move (&(0, 0), Direction::Right).is_none ());
-        assert! (grid.try_move (&(0, 2), Direction::Left).is_none ());
-        assert! (grid.try_move (&(1, 1), Direction::Up).is_none ());
+        assert! (grid.try_move (None, &(0, 0), Direction::Right).is_none ());
+        assert! (grid.try_move (None, &(0, 2), Direction::Left).is_none ());
+        assert! (grid.try_move (None, &(1, 1), Direction::Up).is_none ());
     }
 
     #[test]
@@ -919,12 +1178,29 @@ mod tests {
 
         // Test impassable update
         grid.update_adjacency (&(0, 0));
-        assert! (grid.try_move (&(0, 1), Direction::Left).is_none ());
-        assert! (grid.try_move (&(1, 0), Direction::Up).is_none ());
+        assert! (grid.try_move (None, &(0, 1), Direction::Left).is_none ());
+        assert! (grid.try_move (None, &(1, 0), Direction::Up).is_none ());
         // Test passable update
         grid.update_adjacency (&(1, 2));
-        assert_eq! (grid.try_move (&(0, 2), Direction::Down).unwrap (), ((1, 2), 1));
-        assert_eq! (grid.try_move (&(1, 1), Direction::Right).unwrap (), ((1, 2), 2));
+        assert_eq! (grid.try_move (None, &(0, 2), Direction::Down).unwrap (), ((1, 2), 1));
+        assert_eq! (grid.try_move (None, &(1, 1), Direction::Right).unwrap (), ((1, 2), 2));
+    }
+
+    #[test]
+    fn grid_set_weather () {
+        let scene = generate_scene ();
+        let modifier_10 = scene.get_modifier (&10).clone ();
+        let mut grid = generate_grid ();
+
+        grid.set_weather (Some (modifier_10));
+        assert_eq! (grid.try_move (None, &(0, 0), Direction::Right).unwrap (), ((0, 1), 3));
+        assert_eq! (grid.try_move (None, &(1, 0), Direction::Right).unwrap (), ((1, 1), 4));
+        assert! (grid.try_move (None, &(1, 1), Direction::Right).is_none ());
+        grid.set_weather (None);
+        assert_eq! (grid.try_move (None, &(0, 0), Direction::Right).unwrap (), ((0, 1), 2));
+        assert_eq! (grid.try_move (None, &(1, 0), Direction::Right).unwrap (), ((1, 1), 3));
+        assert! (grid.is_sheltered (&(0, 0)));
+        assert! (!grid.is_sheltered (&(1, 1)));
     }
 
     #[test]
@@ -946,12 +1222,12 @@ mod tests {
 
         grid.place_unit (0, (0, 0));
 
-        assert! (grid.move_unit (0, &[Direction::Up]).is_none ()); // Test out-of-bounds move
-        assert! (grid.move_unit (0, &[Direction::Left]).is_none ()); // Test out-of-bounds move
-        assert! (grid.move_unit (0, &[Direction::Down]).is_none ()); // Test not climbable move
+        assert! (grid.move_unit (&generate_mover (0), &[Direction::Up]).is_none ()); // Test out-of-bounds move
+        assert! (grid.move_unit (&generate_mover (0), &[Direction::Left]).is_none ()); // Test out-of-bounds move
+        assert! (grid.move_unit (&generate_mover (0), &[Direction::Down]).is_none ()); // Test not climbable move
         // Test normal move
         assert_eq! (grid.get_location_faction (&(0, 1)), &FACTION_UNCONTROLLED);
-        let response = grid.move_unit (0, &[Direction::Right]).unwrap ();
+        let response = grid.move_unit (&generate_mover (0), &[Direction::Right]).unwrap ();
         assert_eq! (response.0, (0, 1));
         assert_eq! (response.1, 0);
         assert_eq! (grid.get_unit_location (&0).unwrap (), &(0, 1));
@@ -960,7 +1236,7 @@ mod tests {
         // Test sequential move
         assert_eq! (grid.get_location_faction (&(0, 2)), &FACTION_UNCONTROLLED);
         assert_eq! (grid.get_location_faction (&(1, 1)), &FACTION_UNCONTROLLED);
-        let response = grid.move_unit (0, &[Direction::Right, Direction::Left, Direction::Down]).unwrap (); // Test overlapping move
+        let response = grid.move_unit (&generate_mover (0), &[Direction::Right, Direction::Left, Direction::Down]).unwrap (); // Test overlapping move
         assert_eq! (response.0, (1, 1));
         assert_eq! (response.1, 1);
         assert_eq! (grid.get_unit_location (&0).unwrap (), &(1, 1));
@@ -968,7 +1244,7 @@ mod tests {
         assert_eq! (grid.get_location_faction (&(0, 2)), &0);
         assert_eq! (grid.get_location_faction (&(1, 1)), &0);
         // Test atomic move
-        assert! (grid.move_unit (0, &[Direction::Left, Direction::Right, Direction::Right]).is_none ()); // Test impassable move
+        assert! (grid.move_unit (&generate_mover (0), &[Direction::Left, Direction::Right, Direction::Right]).is_none ()); // Test impassable move
         assert_eq! (grid.get_unit_location (&0).unwrap (), &(1, 1));
     }
 
@@ -979,37 +1255,37 @@ mod tests {
         grid.place_unit (0, (0, 0));
 
         // Test empty move
-        let response = grid.find_unit_movable (&0, 1);
+        let response = grid.find_unit_movable (&generate_mover (0), 1);
         assert_eq! (response.len (), 1);
         assert! (response.contains (&(0, 0)));
         // Test normal move
-        let response = grid.find_unit_movable (&0, 2);
+        let response = grid.find_unit_movable (&generate_mover (0), 2);
         assert_eq! (response.len (), 2);
         assert! (response.contains (&(0, 0)));
         assert! (response.contains (&(0, 1)));
-        let response = grid.find_unit_movable (&0, 3);
+        let response = grid.find_unit_movable (&generate_mover (0), 3);
         assert_eq! (response.len (), 2);
         assert! (response.contains (&(0, 0)));
         assert! (response.contains (&(0, 1)));
-        let response = grid.find_unit_movable (&0, 4);
+        let response = grid.find_unit_movable (&generate_mover (0), 4);
         assert_eq! (response.len (), 4);
         assert! (response.contains (&(0, 0)));
         assert! (response.contains (&(0, 1)));
         assert! (response.contains (&(0, 2)));
         assert! (response.contains (&(1, 1)));
-        let response = grid.find_unit_movable (&0, 5);
+        let response = grid.find_unit_movable (&generate_mover (0), 5);
         assert_eq! (response.len (), 4);
         assert! (response.contains (&(0, 0)));
         assert! (response.contains (&(0, 1)));
         assert! (response.contains (&(0, 2)));
         assert! (response.contains (&(1, 1)));
-        let response = grid.find_unit_movable (&0, 6);
+        let response = grid.find_unit_movable (&generate_mover (0), 6);
         assert_eq! (response.len (), 4);
         assert! (response.contains (&(0, 0)));
         assert! (response.contains (&(0, 1)));
         assert! (response.contains (&(0, 2)));
         assert! (response.contains (&(1, 1)));
-        let response = grid.find_unit_movable (&0, 7);
+        let response = grid.find_unit_movable (&generate_mover (0), 7);
         assert_eq! (response.len (), 5);
         assert! (response.contains (&(0, 0)));
         assert! (response.contains (&(0, 1)));
@@ -1018,6 +1294,110 @@ mod tests {
         assert! (response.contains (&(1, 0)));
     }
 
+    #[test]
+    fn grid_find_unit_reachable () {
+        let mut grid = generate_grid ();
+
+        grid.place_unit (0, (0, 0));
+
+        let response = grid.find_unit_reachable (&generate_mover (0), 7);
+        assert_eq! (response.locations_iter ().collect::<Vec<Location>> (), vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1)]);
+        assert_eq! (response.get_cost (&(0, 0)), Some (0));
+        assert_eq! (response.get_cost (&(0, 2)), Some (4));
+        assert_eq! (response.get_cost (&(1, 0)), Some (7));
+        assert_eq! (response.get_mov_remaining (&(1, 1)), Some (3));
+        assert_eq! (response.get_predecessor (&(0, 0)), None);
+        assert_eq! (response.get_predecessor (&(1, 0)), Some (((1, 1), Direction::Left)));
+        assert_eq! (response.find_movements (&(1, 0)), Some (vec![Direction::Right, Direction::Down, Direction::Left]));
+        // Test unreachable location
+        assert! (!response.is_reachable (&(1, 2)));
+        assert_eq! (response.get_cost (&(1, 2)), None);
+        assert_eq! (response.find_movements (&(1, 2)), None);
+        // Test insufficient MOV
+        let response = grid.find_unit_reachable (&generate_mover (0), 6);
+        assert! (!response.is_reachable (&(1, 0)));
+        assert_eq! (response.get_mov_remaining (&(1, 1)), Some (2));
+    }
+
+    #[test]
+    fn grid_scale_cost () {
+        let mut grid = generate_grid ();
+        let mover = Mover::new (0, 1, &[], &[]);
+
+        grid.place_unit (0, (0, 0));
+
+        // Test terrain multiplier
+        let response = grid.find_unit_reachable (&mover, 7);
+        assert_eq! (response.get_cost (&(0, 2)), Some (4));
+        assert_eq! (response.get_cost (&(1, 1)), Some (6));
+        assert! (!response.is_reachable (&(1, 0)));
+        // Test moving unit
+        assert_eq! (grid.try_move (None, &(0, 1), Direction::Down), Some (((1, 1), 2)));
+        assert_eq! (grid.try_move (Some (&mover), &(0, 1), Direction::Down), Some (((1, 1), 4)));
+    }
+
+    #[test]
+    fn grid_is_controlled () {
+        let mut grid = generate_grid ();
+
+        grid.place_unit (0, (1, 1));
+        grid.place_unit (2, (0, 0));
+
+        assert! (grid.is_controlled (&generate_mover (2), &(0, 1)));
+        assert! (!grid.is_controlled (&generate_mover (2), &(0, 0)));
+        assert! (grid.is_controlled (&generate_mover (0), &(1, 0)));
+        // Test exempt skill
+        assert! (!grid.is_controlled (&generate_mover (3), &(1, 0)));
+        // Test exempt class
+        assert! (!grid.is_controlled (&Mover::new (0, 1, &[2], &[]), &(1, 0)));
+        // Test ally
+        assert! (!grid.is_controlled (&Mover::new (2, 3, &[0], &[]), &(0, 1)));
+    }
+
+    #[test]
+    fn grid_is_stopped () {
+        let mut grid = generate_grid ();
+
+        grid.place_unit (0, (1, 1));
+        grid.place_unit (2, (0, 0));
+
+        assert! (grid.is_stopped (Some (&generate_mover (2)), &(0, 1)));
+        assert! (!grid.is_stopped (None, &(0, 1)));
+        // Test reachable
+        let response = grid.find_unit_reachable (&generate_mover (2), 20);
+        assert! (response.is_reachable (&(0, 1)));
+        assert! (!response.is_reachable (&(0, 2)));
+        // Test path
+        assert! (grid.find_path (&generate_mover (2), &(0, 1), 20).is_some ());
+        assert! (grid.find_path (&generate_mover (2), &(0, 2), 20).is_none ());
+        // Test move
+        assert! (grid.move_unit (&generate_mover (2), &[Direction::Right, Direction::Right]).is_none ());
+        assert_eq! (grid.get_unit_location (&2), Some (&(0, 1)));
+        // Test leaving zone
+        assert! (grid.move_unit (&generate_mover (2), &[Direction::Right]).is_some ());
+    }
+
+    #[test]
+    fn grid_find_path () {
+        let mut grid = generate_grid ();
+
+        grid.place_unit (0, (0, 0));
+
+        // Test empty path
+        assert_eq! (grid.find_path (&generate_mover (0), &(0, 0), 0), Some ((Vec::new (), 0)));
+        // Test normal path
+        assert_eq! (grid.find_path (&generate_mover (0), &(0, 2), 4), Some ((vec![Direction::Right, Direction::Right], 4)));
+        assert_eq! (grid.find_path (&generate_mover (0), &(1, 0), 7), Some ((vec![Direction::Right, Direction::Down, Direction::Left], 7)));
+        // Test insufficient MOV
+        assert! (grid.find_path (&generate_mover (0), &(1, 0), 6).is_none ());
+        // Test impassable path
+        assert! (grid.find_path (&generate_mover (0), &(1, 2), u16::MAX).is_none ());
+        // Test occupied path
+        grid.place_unit (1, (1, 1));
+        assert! (grid.find_path (&generate_mover (0), &(1, 0), 7).is_none ());
+        assert_eq! (grid.find_path_from (None, &(0, 2), &(0, 1), 2), Some ((vec![Direction::Left], 2)));
+    }
+
     #[test]
     fn grid_find_unit_cities () {
         let mut grid = generate_grid ();
@@ -1025,7 +1405,7 @@ mod tests {
         // Test no supply
         grid.place_unit (0, (0, 1));
         assert! (grid.find_unit_cities (&0).is_empty ());
-        grid.move_unit (0, &[Direction::Down]);
+        grid.move_unit (&generate_mover (0), &[Direction::Down]);
         assert! (grid.find_unit_cities (&0).is_empty ());
         // Test contested supply
         grid.place_unit (2, (0, 0));
@@ -1164,13 +1544,55 @@ mod tests {
         assert_eq! (grid.find_units (&(1, 2), Search::Radial (2)).len (), 4);
     }
 
+    #[test]
+    fn grid_find_locations_strike () {
+        let grid = generate_grid ();
+
+        assert_eq! (grid.find_locations_strike (&(0, 0), Area::Single, 1), vec![(0, 0), (0, 1), (1, 0)]);
+        assert_eq! (grid.find_locations_strike (&(0, 0), Area::Radial (1), 1), vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1)]);
+        assert_eq! (grid.find_locations_strike (&(0, 0), Area::Path (0), 1), vec![(0, 1), (1, 0)]);
+    }
+
+    #[test]
+    fn grid_find_unit_threats () {
+        let mut grid = generate_grid ();
+        let mut threat_map = ThreatMap::new (2, 3);
+
+        grid.place_unit (0, (0, 0));
+        grid.place_unit (2, (0, 2));
+        // Test stationary threat
+        grid.find_unit_threats (&mut threat_map, &generate_mover (0), 0, &[(Area::Single, 1)]);
+        assert_eq! (threat_map.locations_iter ().collect::<Vec<Location>> (), vec![(0, 0), (0, 1), (1, 0)]);
+        assert_eq! (threat_map.get_unit_ids (&(0, 1)), &[0]);
+        assert! (!threat_map.is_threatened (&(1, 1)));
+        // Test moving threat
+        grid.find_unit_threats (&mut threat_map, &generate_mover (0), 2, &[(Area::Single, 1)]);
+        assert_eq! (threat_map.get_unit_ids (&(1, 1)), &[0]);
+        assert! (!threat_map.is_threatened (&(1, 2)));
+        // Test multiple threats
+        grid.find_unit_threats (&mut threat_map, &generate_mover (2), 0, &[(Area::Single, 1)]);
+        assert_eq! (threat_map.get_unit_ids (&(0, 1)), &[0, 2]);
+        assert_eq! (threat_map.get_unit_ids (&(1, 2)), &[2]);
+    }
+
+    #[test]
+    fn grid_find_locations_visible () {
+        let grid = generate_grid ();
+
+        assert_eq! (grid.find_locations_visible (&(0, 0), 1), vec![(0, 0), (0, 1), (1, 0)]);
+        // Test blocked sight
+        assert_eq! (grid.find_locations_visible (&(0, 0), 2), vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+        // Test high ground
+        assert_eq! (grid.find_locations_visible (&(1, 0), 0), vec![(0, 0), (0, 1), (1, 0), (1, 1), (1, 2)]);
+    }
+
     #[test]
     fn grid_add_appliable () {
         let scene = generate_scene ();
         let mut grid = generate_grid ();
         let attribute_2 = *scene.get_attribute (&2);
         let attribute_2 = Box::new (attribute_2);
-        let modifier_1 = *scene.get_modifier (&1);
+        let modifier_1 = scene.get_modifier (&1).clone ();
         let modifier_1 = Box::new (modifier_1);
 
         assert! (grid.add_appliable (&(0, 0), attribute_2));
@@ -1213,7 +1635,7 @@ mod tests {
         assert_eq! (response.len (), 1);
         assert! (response.contains (&(0, 2)));
         // Test connected find
-        grid.move_unit (0, &[Direction::Right]);
+        grid.move_unit (&generate_mover (0), &[Direction::Right]);
         let response = grid.find_locations_supplied (&0);
         assert_eq! (response.len (), 3);
         assert! (response.contains (&(0, 0)));
@@ -1252,7 +1674,7 @@ mod tests {
         let mut grid = generate_grid ();
         let attribute_2 = *scene.get_attribute (&2);
         let mut attribute_2 = Box::new (attribute_2);
-        let modifier_2 = *scene.get_modifier (&2);
+        let modifier_2 = scene.get_modifier (&2).clone ();
         let mut modifier_2 = Box::new (modifier_2);
 
         attribute_2.set_applier_id (0);
@@ -2,16 +2,30 @@ mod city;
 pub use self::city::*;
 mod grid;
 pub use self::grid::*;
+mod mover;
+pub use self::mover::*;
+mod positioning;
+pub use self::positioning::*;
+mod reachability;
+pub use self::reachability::*;
 mod terrain;
 pub use self::terrain::*;
+mod threat;
+pub use self::threat::*;
 mod tile;
 pub use self::tile::*;
+mod weather;
+pub use self::weather::*;
+mod zone;
+pub use self::zone::*;
+use serde::{Deserialize, Serialize};
 
 pub const COST_IMPASSABLE: u8 = 0;
 pub const COST_MINIMUM: u8 = 1;
 
 #[derive (Debug)]
 #[derive (Clone, Copy)]
+#[derive (Deserialize, Serialize)]
 pub enum Area {
     Single,
     Radial (u8), // radius
@@ -0,0 +1,40 @@
+use crate::common::ID;
+
+/*
+ * A moving unit, as far as the grid is concerned
+ * Its class scales terrain costs, while its faction's allies and its skills decide zones of control
+ */
+#[derive (Debug)]
+#[derive (Clone)]
+#[derive (PartialEq)]
+pub struct Mover {
+    unit_id: ID,
+    class_id: ID,
+    allies: Vec<ID>,
+    skill_ids: Vec<ID>,
+}
+
+impl Mover {
+    pub fn new (unit_id: ID, class_id: ID, allies: &[ID], skill_ids: &[ID]) -> Self {
+        let allies: Vec<ID> = allies.to_vec ();
+        let skill_ids: Vec<ID> = skill_ids.to_vec ();
+
+        Self { unit_id, class_id, allies, skill_ids }
+    }
+
+    pub fn is_ally (&self, faction_id: &ID) -> bool {
+        self.allies.contains (faction_id)
+    }
+
+    pub fn get_unit_id (&self) -> ID {
+        self.unit_id
+    }
+
+    pub fn get_class_id (&self) -> ID {
+        self.class_id
+    }
+
+    pub fn get_skill_ids (&self) -> &[ID] {
+        &self.skill_ids
+    }
+}
@@ -0,0 +1,150 @@
+use super::{Direction, Location, switch_direction};
+use std::fmt::{self, Display, Formatter};
+
+const FACTOR_HEIGHT: f32 = 0.1; // per level of height difference
+const FACTOR_SIDE: f32 = 0.25;
+const FACTOR_REAR: f32 = 0.5;
+const FACTOR_SURROUND: f32 = 0.1; // per adjacent enemy beyond the first
+const FACTOR_MINIMUM: f32 = 0.5;
+
+// Finds the side of start that end is on, preferring rows over columns
+fn find_direction (start: &Location, end: &Location) -> Direction {
+    if start.0.abs_diff (end.0) >= start.1.abs_diff (end.1) {
+        if end.0 < start.0 {
+            Direction::Up
+        } else {
+            Direction::Down
+        }
+    } else if end.1 < start.1 {
+        Direction::Left
+    } else {
+        Direction::Right
+    }
+}
+
+#[derive (Debug)]
+#[derive (Clone, Copy)]
+#[derive (PartialEq)]
+pub enum Flank {
+    Front,
+    Side,
+    Rear,
+}
+
+impl Flank {
+    // Units that have never moved have no facing, so they can't be flanked
+    pub fn find (facing: Option<Direction>, location_defender: &Location, location_attacker: &Location) -> Self {
+        if let Some (facing) = facing {
+            let direction: Direction = find_direction (location_defender, location_attacker);
+
+            if direction == facing {
+                Self::Front
+            } else if direction == switch_direction (facing) {
+                Self::Rear
+            } else {
+                Self::Side
+            }
+        } else {
+            Self::Front
+        }
+    }
+}
+
+/*
+ * Positional factors of an attack, measured from the attacker against the defender
+ * Height is the attacker's tile height minus the defender's
+ * Surround is the number of directions around the defender occupied by its enemies
+ */
+#[derive (Debug)]
+#[derive (Clone, Copy)]
+#[derive (PartialEq)]
+pub struct Positioning {
+    height: i16,
+    flank: Flank,
+    surround: u8,
+}
+
+impl Positioning {
+    pub const fn new (height: i16, flank: Flank, surround: u8) -> Self {
+        Self { height, flank, surround }
+    }
+
+    pub fn calculate_factor (&self) -> f32 {
+        let factor_height: f32 = (self.height as f32) * FACTOR_HEIGHT;
+        let factor_flank: f32 = match self.flank {
+            Flank::Front => 0.0,
+            Flank::Side => FACTOR_SIDE,
+            Flank::Rear => FACTOR_REAR,
+        };
+        let factor_surround: f32 = (self.surround.saturating_sub (1) as f32) * FACTOR_SURROUND;
+
+        f32::max (1.0 + factor_height + factor_flank + factor_surround, FACTOR_MINIMUM)
+    }
+
+    pub fn get_height (&self) -> i16 {
+        self.height
+    }
+
+    pub fn get_flank (&self) -> Flank {
+        self.flank
+    }
+
+    pub fn get_surround (&self) -> u8 {
+        self.surround
+    }
+
+    pub fn is_surrounded (&self) -> bool {
+        self.surround > 1
+    }
+}
+
+impl Default for Positioning {
+    fn default () -> Self {
+        Self::new (0, Flank::Front, 0)
+    }
+}
+
+impl Display for Positioning {
+    fn fmt (&self, f: &mut Formatter) -> fmt::Result {
+        write! (f, "height {:+}, {:?} attack", self.height, self.flank)?;
+
+        if self.is_surrounded () {
+            write! (f, ", surrounded on {} sides", self.surround)?;
+        }
+
+        Ok (())
+    }
+}
+
+#[cfg (test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flank_find () {
+        // Test no facing
+        assert_eq! (Flank::find (None, &(1, 1), &(0, 1)), Flank::Front);
+        // Test front
+        assert_eq! (Flank::find (Some (Direction::Up), &(1, 1), &(0, 1)), Flank::Front);
+        // Test side
+        assert_eq! (Flank::find (Some (Direction::Up), &(1, 1), &(1, 0)), Flank::Side);
+        assert_eq! (Flank::find (Some (Direction::Up), &(1, 1), &(1, 3)), Flank::Side);
+        // Test rear
+        assert_eq! (Flank::find (Some (Direction::Up), &(1, 1), &(2, 1)), Flank::Rear);
+        assert_eq! (Flank::find (Some (Direction::Up), &(1, 1), &(3, 2)), Flank::Rear);
+    }
+
+    #[test]
+    fn positioning_calculate_factor () {
+        assert_eq! (Positioning::default ().calculate_factor (), 1.0);
+        // Test height
+        assert! (Positioning::new (2, Flank::Front, 0).calculate_factor () > 1.0);
+        assert! (Positioning::new (-2, Flank::Front, 0).calculate_factor () < 1.0);
+        assert_eq! (Positioning::new (-20, Flank::Front, 0).calculate_factor (), FACTOR_MINIMUM);
+        // Test flank
+        assert! (Positioning::new (0, Flank::Rear, 0).calculate_factor () > Positioning::new (0, Flank::Side, 0).calculate_factor ());
+        // Test surround
+        assert_eq! (Positioning::new (0, Flank::Front, 1).calculate_factor (), 1.0);
+        assert! (Positioning::new (0, Flank::Front, 3).calculate_factor () > 1.0);
+    }
+}
@@ -0,0 +1,68 @@
+use super::{Direction, Location};
+
+pub type Reach = (u16, Option<(Location, Direction)>); // cost, predecessor
+
+#[derive (Debug)]
+#[derive (Clone)]
+pub struct Reachability {
+    mov: u16,
+    reaches: Vec<Vec<Option<Reach>>>,
+}
+
+impl Reachability {
+    pub fn new (mov: u16, reaches: Vec<Vec<Option<Reach>>>) -> Self {
+        Self { mov, reaches }
+    }
+
+    fn get_reach (&self, location: &Location) -> Option<&Reach> {
+        self.reaches.get (location.0)
+                .and_then (|r: &Vec<Option<Reach>>| r.get (location.1))
+                .and_then (|r: &Option<Reach>| r.as_ref ())
+    }
+
+    pub fn is_reachable (&self, location: &Location) -> bool {
+        self.get_reach (location).is_some ()
+    }
+
+    pub fn get_cost (&self, location: &Location) -> Option<u16> {
+        self.get_reach (location).map (|r: &Reach| r.0)
+    }
+
+    pub fn get_mov_remaining (&self, location: &Location) -> Option<u16> {
+        self.get_cost (location).map (|c: u16| self.mov - c)
+    }
+
+    pub fn get_predecessor (&self, location: &Location) -> Option<(Location, Direction)> {
+        self.get_reach (location).and_then (|r: &Reach| r.1)
+    }
+
+    pub fn find_movements (&self, location: &Location) -> Option<Vec<Direction>> {
+        let mut movements: Vec<Direction> = Vec::new ();
+        let mut location: Location = *location;
+
+        if !self.is_reachable (&location) {
+            return None
+        }
+
+        while let Some ((previous, direction)) = self.get_predecessor (&location) {
+            movements.push (direction);
+            location = previous;
+        }
+
+        movements.reverse ();
+
+        Some (movements)
+    }
+
+    pub fn locations_iter (&self) -> impl Iterator<Item = Location> + '_ {
+        self.reaches.iter ().enumerate ().flat_map (|(i, row): (usize, &Vec<Option<Reach>>)|
+            row.iter ().enumerate ().filter_map (move |(j, reach): (usize, &Option<Reach>)|
+                reach.map (|_| (i, j))
+            )
+        )
+    }
+
+    pub fn get_mov (&self) -> u16 {
+        self.mov
+    }
+}
@@ -1,15 +1,51 @@
-use crate::common::ID;
+use crate::character::{Element, MULTIPLIER_BASE};
+use crate::common::{ID, Information};
+use serde::{Deserialize, Serialize};
 
+/*
+ * Occupants are protected and supplied according to their terrain
+ * Defence bonus and magic resistance are percentages out of MULTIPLIER_BASE, so a defence bonus of 25 adds 25%
+ * MRL recovery and SPL drain are applied at the end of every turn
+ */
 #[derive (Debug)]
-#[derive (Clone, Copy)]
+#[derive (Clone)]
+#[derive (Deserialize, Serialize)]
 pub struct Terrain {
     modifier_id: Option<ID>,
     cost: u8,
+    #[serde (default)]
+    bonus_defence: u16,
+    #[serde (default)]
+    resistance_magic: u16,
+    #[serde (default)]
+    recover_mrl: u16,
+    #[serde (default)]
+    drain_spl: u16,
+    #[serde (default)]
+    bonus_sight: u8,
+    #[serde (default)]
+    affinity: Option<Element>,
+    #[serde (default)]
+    information: Information,
 }
 
 impl Terrain {
-    pub const fn new (modifier_id: Option<ID>, cost: u8 ) -> Self {
-        Self { modifier_id, cost }
+    #[allow (clippy::too_many_arguments)]
+    pub fn new (modifier_id: Option<ID>, cost: u8, bonus_defence: u16, resistance_magic: u16, recover_mrl: u16, drain_spl: u16, bonus_sight: u8, affinity: Option<Element>, information: Information) -> Self {
+        Self { modifier_id, cost, bonus_defence, resistance_magic, recover_mrl, drain_spl, bonus_sight, affinity, information }
+    }
+
+    pub fn scale_defence (&self, def: u16) -> u16 {
+        let base: u32 = MULTIPLIER_BASE as u32;
+
+        ((def as u32) * (base + self.bonus_defence as u32) / base) as u16
+    }
+
+    pub fn scale_magic (&self, damage: u16) -> u16 {
+        let base: u32 = MULTIPLIER_BASE as u32;
+        let resistance: u32 = u32::min (self.resistance_magic as u32, base);
+
+        ((damage as u32) * (base - resistance) / base) as u16
     }
 
     pub fn get_modifier_id (&self) -> Option<ID> {
@@ -19,4 +55,53 @@ impl Terrain {
     pub fn get_cost (&self) -> u8 {
         self.cost
     }
+
+    pub fn get_bonus_defence (&self) -> u16 {
+        self.bonus_defence
+    }
+
+    pub fn get_resistance_magic (&self) -> u16 {
+        self.resistance_magic
+    }
+
+    pub fn get_recover_mrl (&self) -> u16 {
+        self.recover_mrl
+    }
+
+    pub fn get_drain_spl (&self) -> u16 {
+        self.drain_spl
+    }
+
+    pub fn get_bonus_sight (&self) -> u8 {
+        self.bonus_sight
+    }
+
+    pub fn get_affinity (&self) -> Option<Element> {
+        self.affinity
+    }
+
+    pub fn get_information (&self) -> &Information {
+        &self.information
+    }
+}
+
+#[cfg (test)]
+mod tests {
+    use crate::tests::generate_scene;
+
+    #[test]
+    fn terrain_scale_defence () {
+        let scene = generate_scene ();
+
+        assert_eq! (scene.get_terrain (&0).scale_defence (20), 20);
+        assert_eq! (scene.get_terrain (&1).scale_defence (20), 25);
+    }
+
+    #[test]
+    fn terrain_scale_magic () {
+        let scene = generate_scene ();
+
+        assert_eq! (scene.get_terrain (&0).scale_magic (10), 10);
+        assert_eq! (scene.get_terrain (&1).scale_magic (10), 8);
+    }
 }
@@ -0,0 +1,40 @@
+use super::Location;
+use crate::common::ID;
+
+#[derive (Debug)]
+#[derive (Clone)]
+pub struct ThreatMap {
+    unit_ids: Vec<Vec<Vec<ID>>>, // units that can strike each tile
+}
+
+impl ThreatMap {
+    pub fn new (length: usize, width: usize) -> Self {
+        let unit_ids: Vec<Vec<Vec<ID>>> = vec![vec![Vec::new (); width]; length];
+
+        Self { unit_ids }
+    }
+
+    pub fn add_threat (&mut self, location: &Location, unit_id: ID) {
+        let unit_ids: &mut Vec<ID> = &mut self.unit_ids[location.0][location.1];
+
+        if let Err (index) = unit_ids.binary_search (&unit_id) {
+            unit_ids.insert (index, unit_id);
+        }
+    }
+
+    pub fn get_unit_ids (&self, location: &Location) -> &[ID] {
+        &self.unit_ids[location.0][location.1]
+    }
+
+    pub fn is_threatened (&self, location: &Location) -> bool {
+        !self.unit_ids[location.0][location.1].is_empty ()
+    }
+
+    pub fn locations_iter (&self) -> impl Iterator<Item = Location> + '_ {
+        self.unit_ids.iter ().enumerate ().flat_map (|(i, row): (usize, &Vec<Vec<ID>>)|
+            row.iter ().enumerate ().filter_map (move |(j, unit_ids): (usize, &Vec<ID>)|
+                (!unit_ids.is_empty ()).then_some ((i, j))
+            )
+        )
+    }
+}
@@ -1,17 +1,36 @@
 use super::{COST_IMPASSABLE, COST_MINIMUM};
-use crate::common::{ID, Scene, Target, Timed};
+use crate::common::{ID, Information, Scene, Target, Timed};
 use crate::dynamic::{Adjustment, Appliable, AppliableKind, Applier, Attribute, Dynamic, Modifier, StatisticKind, Trigger};
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 use std::fmt::{self, Display, Formatter};
 
 const CLIMB_MAX: u8 = 2;
 
+fn apply_modifier (cost: u8, modifier: &Modifier) -> u8 {
+    let (statistic, value, is_add): Adjustment = modifier.get_adjustments ()[0];
+
+    match statistic {
+        StatisticKind::Tile (is_flat) => if is_flat {
+            value as u8
+        } else if is_add {
+            cost.saturating_add (value as u8)
+        } else {
+            u8::max (cost.saturating_sub (value as u8), COST_MINIMUM)
+        }
+        _ => panic! ("Invalid statistic {:?}", statistic),
+    }
+}
+
 #[derive (Debug)]
 #[derive (Clone)]
+#[derive (Deserialize, Serialize)]
 pub struct Tile {
+    #[serde (skip, default = "Scene::placeholder")]
     scene: Rc<Scene>,
     modifier: Option<Modifier>,
-    // modifier_weather: Option<Modifier>,
+    #[serde (default)]
+    modifier_weather: Option<Modifier>,
     attribute: Option<Attribute>,
     terrain_id: ID,
     height: u8,
@@ -24,32 +43,23 @@ pub struct Tile {
 impl Tile {
     pub fn new (scene: Rc<Scene>, terrain_id: ID, height: u8, city_id: Option<ID>) -> Self {
         let modifier: Option<Modifier> = None;
+        let modifier_weather: Option<Modifier> = None;
         let attribute: Option<Attribute> = None;
         let is_recruited: bool = false;
         let applier_id_modifier: Option<ID> = None;
         let applier_id_attribute: Option<ID> = None;
 
-        Self { scene, modifier, attribute, terrain_id, height, city_id, is_recruited, applier_id_modifier, applier_id_attribute }
+        Self { scene, modifier, modifier_weather, attribute, terrain_id, height, city_id, is_recruited, applier_id_modifier, applier_id_attribute }
     }
 
     pub fn get_cost (&self) -> u8 {
         let cost: u8 = self.scene.get_terrain (&self.terrain_id).get_cost ();
+        let cost: u8 = self.modifier.as_ref ().map_or (cost, |m: &Modifier| apply_modifier (cost, m));
 
-        if let Some (modifier) = self.modifier {
-            let (statistic, value, is_add): Adjustment = modifier.get_adjustments ()[0];
-
-            match statistic {
-                StatisticKind::Tile (is_flat) => if is_flat {
-                    value as u8
-                } else if is_add {
-                    cost + (value as u8)
-                } else {
-                    u8::max (cost.saturating_sub (value as u8), COST_MINIMUM)
-                }
-                _ => panic! ("Invalid statistic {:?}", statistic),
-            }
-        } else {
-            cost
+        // Weather never opens up impassable terrain
+        match &self.modifier_weather {
+            Some (modifier) if cost != COST_IMPASSABLE => apply_modifier (cost, modifier),
+            _ => cost,
         }
     }
 
@@ -80,7 +90,11 @@ impl Tile {
     }
 
     pub fn get_modifier (&self) -> Option<Modifier> {
-        self.modifier
+        self.modifier.clone ()
+    }
+
+    pub fn get_modifier_weather (&self) -> Option<&Modifier> {
+        self.modifier_weather.as_ref ()
     }
 
     pub fn get_height (&self) -> u8 {
@@ -103,6 +117,18 @@ impl Tile {
         self.applier_id_attribute
     }
 
+    pub fn get_scene (&self) -> &Rc<Scene> {
+        &self.scene
+    }
+
+    pub fn set_scene (&mut self, scene: Rc<Scene>) {
+        self.scene = scene;
+    }
+
+    pub fn set_modifier_weather (&mut self, modifier_weather: Option<Modifier>) {
+        self.modifier_weather = modifier_weather;
+    }
+
     pub fn set_recruited (&mut self, is_recruited: bool) {
         self.is_recruited = is_recruited;
     }
@@ -118,8 +144,8 @@ impl Dynamic for Tile {
                 let adjustment: Adjustment = modifier.get_adjustments ()[0];
     
                 if let StatisticKind::Tile ( .. ) = adjustment.0 {
-                    self.modifier = Some (modifier);
                     self.applier_id_modifier = modifier.get_applier_id ();
+                    self.modifier = Some (modifier);
     
                     true
                 } else {
@@ -154,7 +180,7 @@ impl Dynamic for Tile {
     fn remove_appliable (&mut self, appliable: AppliableKind) -> bool {
         match appliable {
             AppliableKind::Modifier (modifier_id) => {
-                if let Some (modifier) = self.modifier {
+                if let Some (modifier) = &self.modifier {
                     if modifier.get_id () == modifier_id {
                         self.modifier = None;
                         self.applier_id_modifier = None;
@@ -190,11 +216,11 @@ impl Dynamic for Tile {
     }
 
     fn decrement_durations (&mut self) {
-        if let Some (mut m) = self.modifier {
+        if let Some (mut m) = self.modifier.take () {
             self.modifier = if m.decrement_duration () {
                 Some (m)
             } else {
-                m.get_next_id ().map (|n: ID| *self.scene.get_modifier (&n))
+                m.get_next_id ().map (|n: ID| self.scene.get_modifier (&n).clone ())
             };
         }
 
@@ -221,7 +247,9 @@ impl Applier for Tile {
 impl Display for Tile {
     fn fmt (&self, f: &mut Formatter<'_>) -> fmt::Result {
         let mut display: String = String::from ("");
+        let information: &Information = self.scene.get_terrain (&self.terrain_id).get_information ();
 
+        display.push_str (&format! ("{}: {} ", information.get_name (), information.get_description ()));
         display.push_str (&format! ("(Modifier: {:?}) ", self.modifier));
         display.push_str (&format! ("(Attribute: {:?})", self.attribute));
 
@@ -230,6 +258,8 @@ impl Display for Tile {
 }
 
 #[derive (Debug)]
+#[derive (Clone)]
+#[derive (Deserialize, Serialize)]
 pub struct TileBuilder {
     terrain_id: ID,
     height: u8,
@@ -248,6 +278,10 @@ impl TileBuilder {
     pub fn get_terrain_id (&self) -> ID {
         self.terrain_id
     }
+
+    pub fn get_city_id (&self) -> Option<ID> {
+        self.city_id
+    }
 }
 
 #[cfg (test)]
@@ -258,11 +292,11 @@ mod tests {
 
     fn generate_modifiers () -> (Box<Modifier>, Box<Modifier>, Box<Modifier>) {
         let scene = generate_scene ();
-        let modifier_0 = *scene.get_modifier (&0);
+        let modifier_0 = scene.get_modifier (&0).clone ();
         let modifier_0 = Box::new (modifier_0);
-        let modifier_1 = *scene.get_modifier (&1);
+        let modifier_1 = scene.get_modifier (&1).clone ();
         let modifier_1 = Box::new (modifier_1);
-        let modifier_2 = *scene.get_modifier (&2);
+        let modifier_2 = scene.get_modifier (&2).clone ();
         let modifier_2 = Box::new (modifier_2);
 
         (modifier_0, modifier_1, modifier_2)
@@ -292,6 +326,25 @@ mod tests {
         assert_eq! (tile_2.get_cost (), 0);
     }
 
+    #[test]
+    fn tile_set_modifier_weather () {
+        let scene = generate_scene ();
+        let mut tile_1 = Tile::new (Rc::clone (&scene), 1, 0, None);
+        let mut tile_2 = Tile::new (Rc::clone (&scene), 2, 0, None);
+        let (modifier_0, modifier_1, _) = generate_modifiers ();
+
+        tile_1.set_modifier_weather (Some (*modifier_0.clone ()));
+        assert_eq! (tile_1.get_cost (), 3);
+        // Test terrain and weather modifiers
+        tile_1.add_appliable (modifier_1);
+        assert_eq! (tile_1.get_cost (), 2);
+        tile_1.set_modifier_weather (None);
+        assert_eq! (tile_1.get_cost (), 1);
+        // Test impassable tile
+        tile_2.set_modifier_weather (Some (*modifier_0));
+        assert_eq! (tile_2.get_cost (), 0);
+    }
+
     #[test]
     fn tile_is_impassable () {
         let scene = generate_scene ();
@@ -422,10 +475,10 @@ mod tests {
         tile.add_appliable (modifier_2);
         tile.decrement_durations ();
         assert! (tile.modifier.is_some ());
-        assert_eq! (tile.modifier.unwrap ().get_next_id ().unwrap (), 0);
+        assert_eq! (tile.modifier.as_ref ().unwrap ().get_next_id ().unwrap (), 0);
         tile.decrement_durations ();
         assert! (tile.modifier.is_some ());
-        assert! (tile.modifier.unwrap ().get_next_id ().is_none ());
+        assert! (tile.modifier.as_ref ().unwrap ().get_next_id ().is_none ());
 
         // Test empty attribute
         tile.decrement_durations ();
@@ -445,4 +498,14 @@ mod tests {
         tile.decrement_durations ();
         assert! (tile.attribute.is_some ());
     }
+
+    #[test]
+    fn tile_fmt () {
+        let scene = generate_scene ();
+        let tile_0 = Tile::new (Rc::clone (&scene), 0, 0, None);
+        let tile_2 = Tile::new (Rc::clone (&scene), 2, 0, None);
+
+        assert! (tile_0.to_string ().starts_with ("[ Plains: Open ground offering no cover "));
+        assert! (tile_2.to_string ().starts_with ("[ Mountains: Impassable peaks "));
+    }
 }
@@ -0,0 +1,45 @@
+use crate::common::ID;
+use serde::{Deserialize, Serialize};
+
+#[derive (Debug)]
+#[derive (Clone, Copy)]
+#[derive (PartialEq)]
+#[derive (Deserialize, Serialize)]
+pub enum WeatherKind {
+    Clear,
+    Rain,
+    Snow,
+    Fog,
+    Heat,
+}
+
+/*
+ * Weather applies to the whole map
+ * Units in cities are sheltered from the unit modifier
+ */
+#[derive (Debug)]
+#[derive (Clone, Copy)]
+#[derive (Deserialize, Serialize)]
+pub struct Weather {
+    kind: WeatherKind,
+    modifier_tile_id: Option<ID>,
+    modifier_unit_id: Option<ID>,
+}
+
+impl Weather {
+    pub const fn new (kind: WeatherKind, modifier_tile_id: Option<ID>, modifier_unit_id: Option<ID>) -> Self {
+        Self { kind, modifier_tile_id, modifier_unit_id }
+    }
+
+    pub fn get_kind (&self) -> WeatherKind {
+        self.kind
+    }
+
+    pub fn get_modifier_tile_id (&self) -> Option<ID> {
+        self.modifier_tile_id
+    }
+
+    pub fn get_modifier_unit_id (&self) -> Option<ID> {
+        self.modifier_unit_id
+    }
+}
@@ -0,0 +1,58 @@
+use crate::common::ID;
+use serde::{Deserialize, Serialize};
+
+#[derive (Debug)]
+#[derive (Clone, Copy)]
+#[derive (Default)]
+#[derive (PartialEq)]
+#[derive (Deserialize, Serialize)]
+pub enum ZoneRule {
+    #[default]
+    Disabled,
+    Stop, // entering a zone ends movement
+    Toll (u8), // entering a zone costs extra MOV
+}
+
+/*
+ * Tiles adjacent to a unit's enemies are in their zone of control
+ * Units can always leave the zone they start moving from
+ * Exempt classes and skills ignore zones entirely
+ */
+#[derive (Debug)]
+#[derive (Clone)]
+#[derive (Default)]
+#[derive (PartialEq)]
+#[derive (Deserialize, Serialize)]
+pub struct ZoneOfControl {
+    rule: ZoneRule,
+    #[serde (default)]
+    class_ids_exempt: Vec<ID>,
+    #[serde (default)]
+    skill_ids_exempt: Vec<ID>,
+}
+
+impl ZoneOfControl {
+    pub fn new (rule: ZoneRule, class_ids_exempt: &[ID], skill_ids_exempt: &[ID]) -> Self {
+        let class_ids_exempt: Vec<ID> = class_ids_exempt.to_vec ();
+        let skill_ids_exempt: Vec<ID> = skill_ids_exempt.to_vec ();
+
+        Self { rule, class_ids_exempt, skill_ids_exempt }
+    }
+
+    pub fn is_exempt (&self, class_id: &ID, skill_ids: &[ID]) -> bool {
+        self.class_ids_exempt.contains (class_id)
+                || skill_ids.iter ().any (|s: &ID| self.skill_ids_exempt.contains (s))
+    }
+
+    pub fn get_rule (&self) -> ZoneRule {
+        self.rule
+    }
+
+    pub fn get_class_ids_exempt (&self) -> &[ID] {
+        &self.class_ids_exempt
+    }
+
+    pub fn get_skill_ids_exempt (&self) -> &[ID] {
+        &self.skill_ids_exempt
+    }
+}
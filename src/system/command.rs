@@ -0,0 +1,32 @@
+use super::Action;
+use crate::common::ID;
+use crate::map::{Direction, Location};
+use serde::{Deserialize, Serialize};
+
+#[derive (Debug)]
+#[derive (Clone, Copy)]
+#[derive (PartialEq)]
+#[derive (Deserialize, Serialize)]
+pub enum Command {
+    Action (Action),
+    Direction (Direction),
+    Location (Location),
+    Previous,
+    Next,
+    Confirm,
+    Cancel,
+    // Attacks a target (first) with a weapon (second) without going through the prompts
+    Attack (ID, ID),
+}
+
+// Kind of command expected by the current state
+#[derive (Debug)]
+#[derive (Clone, Copy)]
+#[derive (PartialEq)]
+pub enum Prompt {
+    Action,
+    Index,
+    Direction,
+    Movement,
+    Confirmation,
+}
@@ -0,0 +1,226 @@
+use super::{Action, Command, Game, Prompt};
+use crate::character::{Skill, Tool, Weapon};
+use crate::common::{ID, Target};
+use crate::dynamic::Applier;
+use crate::map::{Area, Direction, Location};
+
+fn send (game: &mut Game, commands: &mut Vec<Command>, input: Command) -> bool {
+    commands.push (input);
+
+    game.update (input).is_turn_over ()
+}
+
+fn send_index (game: &mut Game, commands: &mut Vec<Command>, index: usize) {
+    for _ in 0 .. index {
+        send (game, commands, Command::Next);
+    }
+
+    send (game, commands, Command::Confirm);
+}
+
+#[derive (Debug)]
+#[derive (Clone, Copy)]
+#[derive (PartialEq)]
+pub enum Choice {
+    Unit (ID),
+    Direction (Direction),
+    Location (Location),
+}
+
+#[derive (Debug)]
+#[derive (Clone)]
+#[derive (PartialEq)]
+pub struct Decision {
+    movements: Vec<Direction>,
+    action: Action,
+    skill_magic_id: Option<ID>,
+    choice: Option<Choice>,
+}
+
+impl Decision {
+    pub fn new (movements: Vec<Direction>, action: Action, skill_magic_id: Option<ID>, choice: Option<Choice>) -> Self {
+        assert! (!matches! (action, Action::Move | Action::Weapon));
+
+        Self { movements, action, skill_magic_id, choice }
+    }
+
+    fn choose_unit (&self, game: &mut Game, commands: &mut Vec<Command>, potential_ids: &[ID]) {
+        match self.choice {
+            Some (Choice::Unit (target_id)) => if let Some (index) = potential_ids.iter ().position (|p: &ID| *p == target_id) {
+                send_index (game, commands, index);
+            }
+            Some (Choice::Direction (direction)) => {
+                send (game, commands, Command::Direction (direction));
+            }
+            _ => (),
+        }
+    }
+
+    fn choose_location (&self, game: &mut Game, commands: &mut Vec<Command>) {
+        match self.choice {
+            Some (Choice::Location (target_location)) => {
+                send (game, commands, Command::Location (target_location));
+                send (game, commands, Command::Confirm);
+            }
+            Some (Choice::Direction (direction)) => {
+                send (game, commands, Command::Direction (direction));
+            }
+            _ => {
+                send (game, commands, Command::Confirm);
+            }
+        }
+    }
+
+    fn play_attack (&self, game: &mut Game, commands: &mut Vec<Command>, unit_id: ID) -> bool {
+        send (game, commands, Command::Action (Action::Attack));
+
+        if game.get_prompt () == Prompt::Action {
+            return false
+        }
+
+        let (target, area, range): (Target, Area, u8) = {
+            let weapon: &Weapon = game.get_unit (&unit_id).get_weapon ();
+
+            (weapon.get_target (), weapon.get_area (), weapon.get_range ())
+        };
+        let potential_ids: Vec<ID> = game.find_units_range (unit_id, target, area, range);
+
+        self.choose_unit (game, commands, &potential_ids);
+
+        game.get_prompt () == Prompt::Confirmation && send (game, commands, Command::Confirm)
+    }
+
+    fn play_skill (&self, game: &mut Game, commands: &mut Vec<Command>, unit_id: ID, skill_id: ID) -> bool {
+        let skill_ids: Vec<ID> = game.get_unit (&unit_id).get_skill_ids_actionable ();
+        let index: usize = match skill_ids.iter ().position (|s: &ID| *s == skill_id) {
+            Some (index) => index,
+            None => return false,
+        };
+
+        send (game, commands, Command::Action (Action::Skill));
+
+        if game.get_prompt () == Prompt::Action {
+            return false
+        }
+
+        send_index (game, commands, index);
+
+        let (target, area, range): (Target, Area, u8) = {
+            let skill: &Skill = game.get_scene ().get_skill (&skill_id);
+
+            (skill.get_target (), skill.get_area (), skill.get_range ())
+        };
+        let potential_ids: Vec<ID> = game.find_units_range (unit_id, target, area, range);
+
+        self.choose_unit (game, commands, &potential_ids);
+
+        game.get_prompt () == Prompt::Confirmation && send (game, commands, Command::Confirm)
+    }
+
+    fn play_magic (&self, game: &mut Game, commands: &mut Vec<Command>, unit_id: ID, magic_id: ID) -> bool {
+        let index: usize = match game.get_unit (&unit_id).get_magic_ids ().iter ().position (|m: &ID| *m == magic_id) {
+            Some (index) => index,
+            None => return false,
+        };
+
+        send (game, commands, Command::Action (Action::Magic));
+
+        if game.get_prompt () == Prompt::Action {
+            return false
+        }
+
+        send_index (game, commands, index);
+        self.choose_location (game, commands);
+
+        game.get_prompt () == Prompt::Confirmation && send (game, commands, Command::Confirm)
+    }
+
+    pub fn play (&self, game: &mut Game) -> Vec<Command> {
+        let unit_id: ID = game.get_unit_id_active ();
+        let mut commands: Vec<Command> = Vec::new ();
+
+        if !self.movements.is_empty () {
+            send (game, &mut commands, Command::Action (Action::Move));
+
+            for direction in self.movements.iter () {
+                send (game, &mut commands, Command::Direction (*direction));
+            }
+
+            send (game, &mut commands, Command::Confirm);
+        }
+
+        let is_end: bool = match (self.action, self.skill_magic_id) {
+            (Action::Attack, _) => self.play_attack (game, &mut commands, unit_id),
+            (Action::Skill, Some (skill_id)) => self.play_skill (game, &mut commands, unit_id, skill_id),
+            (Action::Magic, Some (magic_id)) => self.play_magic (game, &mut commands, unit_id, magic_id),
+            _ => false,
+        };
+
+        if !is_end {
+            // Back out of any unfinished action, then wait
+            while game.get_prompt () != Prompt::Action {
+                send (game, &mut commands, Command::Cancel);
+            }
+
+            send (game, &mut commands, Command::Action (Action::Wait));
+        }
+
+        commands
+    }
+
+    pub fn get_movements (&self) -> &[Direction] {
+        &self.movements
+    }
+
+    pub fn get_action (&self) -> Action {
+        self.action
+    }
+
+    pub fn get_skill_magic_id (&self) -> Option<ID> {
+        self.skill_magic_id
+    }
+
+    pub fn get_choice (&self) -> Option<Choice> {
+        self.choice
+    }
+}
+
+impl Default for Decision {
+    fn default () -> Self {
+        Self::new (Vec::new (), Action::Wait, None, None)
+    }
+}
+
+#[cfg (test)]
+mod tests {
+    use super::*;
+    use crate::character::UnitStatistic;
+    use crate::tests::generate_game;
+
+    #[test]
+    fn decision_play () {
+        let mut game = generate_game ();
+        let hlt_2 = game.get_unit (&2).get_statistic (UnitStatistic::HLT).0;
+        let decision = Decision::new (Vec::new (), Action::Attack, None, Some (Choice::Unit (2)));
+        let commands = decision.play (&mut game);
+
+        assert_eq! (commands, vec![Command::Action (Action::Attack), Command::Confirm, Command::Confirm]);
+        assert! (game.get_unit (&2).get_statistic (UnitStatistic::HLT).0 < hlt_2);
+        assert_eq! (game.get_number_turns (), 1);
+        // Test movement
+        let unit_id = game.get_unit_id_active ();
+        let decision = Decision::new (vec![Direction::Right], Action::Wait, None, None);
+        let commands = decision.play (&mut game);
+
+        assert_eq! (unit_id, 2);
+        assert_eq! (commands, vec![Command::Action (Action::Move), Command::Direction (Direction::Right), Command::Confirm, Command::Action (Action::Wait)]);
+        assert_eq! (game.get_grid ().get_unit_location (&2), Some (&(1, 1)));
+        assert_eq! (game.get_number_turns (), 2);
+        // Test fallback to wait
+        let decision = Decision::new (Vec::new (), Action::Skill, Some (ID::MAX), None);
+        let commands = decision.play (&mut game);
+
+        assert_eq! (commands, vec![Command::Action (Action::Wait)]);
+        assert_eq! (game.get_number_turns (), 3);
+    }
+}
@@ -0,0 +1,147 @@
+use crate::character::{Unit, UnitStatistic};
+use crate::common::ID;
+use crate::dynamic::AppliableKind;
+use crate::map::Positioning;
+use std::fmt::{self, Display};
+use UnitStatistic::{MRL, HLT, SPL, ORG};
+
+#[derive (Debug)]
+#[derive (Clone)]
+#[derive (PartialEq)]
+pub struct UnitForecast {
+    unit_id: ID,
+    loss_mrl: u16,
+    loss_hlt: u16,
+    loss_spl: u16,
+    loss_org: u16,
+    is_retreat: bool,
+    is_rout: bool,
+    is_alive: bool,
+    appliables: Vec<AppliableKind>, // appliables that would be added to the unit
+}
+
+impl UnitForecast {
+    pub fn new (before: &Unit, after: &Unit, appliables: Vec<AppliableKind>) -> Self {
+        let unit_id: ID = before.get_id ();
+        let calculate_loss = |statistic: UnitStatistic| -> u16 {
+            before.get_statistic (statistic).0.saturating_sub (after.get_statistic (statistic).0)
+        };
+        let loss_mrl: u16 = calculate_loss (MRL);
+        let loss_hlt: u16 = calculate_loss (HLT);
+        let loss_spl: u16 = calculate_loss (SPL);
+        let loss_org: u16 = calculate_loss (ORG);
+        let is_retreat: bool = after.is_retreat ();
+        let is_rout: bool = after.is_rout ();
+        let is_alive: bool = after.is_alive ();
+
+        Self { unit_id, loss_mrl, loss_hlt, loss_spl, loss_org, is_retreat, is_rout, is_alive, appliables }
+    }
+
+    pub fn get_unit_id (&self) -> ID {
+        self.unit_id
+    }
+
+    pub fn get_loss (&self, statistic: UnitStatistic) -> u16 {
+        match statistic {
+            MRL => self.loss_mrl,
+            HLT => self.loss_hlt,
+            SPL => self.loss_spl,
+            ORG => self.loss_org,
+            _ => panic! ("Invalid statistic {:?}", statistic),
+        }
+    }
+
+    pub fn is_retreat (&self) -> bool {
+        self.is_retreat
+    }
+
+    pub fn is_rout (&self) -> bool {
+        self.is_rout
+    }
+
+    pub fn is_alive (&self) -> bool {
+        self.is_alive
+    }
+
+    pub fn get_appliables (&self) -> &[AppliableKind] {
+        &self.appliables
+    }
+}
+
+impl Display for UnitForecast {
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write! (f, "{}: -{} MRL, -{} HLT, -{} SPL, -{} ORG", self.unit_id, self.loss_mrl, self.loss_hlt, self.loss_spl, self.loss_org)?;
+
+        if !self.is_alive {
+            write! (f, " (dies)")?;
+        } else if self.is_retreat {
+            write! (f, " (retreats)")?;
+        } else if self.is_rout {
+            write! (f, " (routs)")?;
+        }
+
+        if !self.appliables.is_empty () {
+            write! (f, " {:?}", self.appliables)?;
+        }
+
+        Ok (())
+    }
+}
+
+#[derive (Debug)]
+#[derive (Clone)]
+#[derive (PartialEq)]
+pub struct Forecast {
+    user: UnitForecast,
+    targets: Vec<UnitForecast>,
+    retaliator_ids: Vec<ID>, // targets that would counterattack
+    positionings: Vec<(ID, Positioning)>, // target, positioning against target
+}
+
+impl Forecast {
+    pub fn new (user: UnitForecast, targets: Vec<UnitForecast>, retaliator_ids: Vec<ID>, positionings: Vec<(ID, Positioning)>) -> Self {
+        Self { user, targets, retaliator_ids, positionings }
+    }
+
+    pub fn get_user (&self) -> &UnitForecast {
+        &self.user
+    }
+
+    pub fn get_targets (&self) -> &[UnitForecast] {
+        &self.targets
+    }
+
+    pub fn get_target (&self, unit_id: &ID) -> Option<&UnitForecast> {
+        self.targets.iter ().find (|t: &&UnitForecast| t.get_unit_id () == *unit_id)
+    }
+
+    pub fn get_positioning (&self, unit_id: &ID) -> Option<&Positioning> {
+        self.positionings.iter ()
+                .find (|(t, _): &&(ID, Positioning)| t == unit_id)
+                .map (|(_, p): &(ID, Positioning)| p)
+    }
+
+    pub fn is_retaliator (&self, unit_id: &ID) -> bool {
+        self.retaliator_ids.contains (unit_id)
+    }
+}
+
+impl Display for Forecast {
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for target in self.targets.iter () {
+            write! (f, "{}", target)?;
+
+            if let Some (positioning) = self.get_positioning (&target.get_unit_id ()) {
+                write! (f, " [{}]", positioning)?;
+            }
+
+            if self.is_retaliator (&target.get_unit_id ()) {
+                write! (f, " (counterattacks)")?;
+            }
+
+            writeln! (f)?;
+        }
+
+        write! (f, "Self: {}", self.user)
+    }
+}
@@ -1,14 +1,36 @@
-use super::{ActionValidator, ConfirmationValidator, DirectionValidator, IndexValidator, MovementValidator, RenderContext, Turn, Validator};
-use crate::character::{Faction, FactionBuilder, Magic, Skill, Tool, Unit, UnitBuilder, UnitStatistic, UnitStatistics, Weapon};
-use crate::common::{FACTOR_ATTACK, FACTOR_MAGIC, FACTOR_SKILL, FACTOR_WAIT, ID, Scene, Target};
-use crate::dynamic::{Appliable, AppliableKind, Applier, Dynamic};
-use crate::map::{Area, Direction, Grid, Location, Search};
-use sdl2::keyboard::Keycode;
+use super::{ActionValidator, Command, ConfirmationValidator, DirectionValidator, Forecast, IndexValidator, Movement, MovementValidator, Prompt, RenderContext, Save, Turn, UnitForecast, Validator};
+use crate::character::{Element, ElementMatrix, Faction, FactionBuilder, Magic, MULTIPLIER_BASE, Retaliation, RetaliationOrder, Skill, Succession, Tool, Unit, UnitBuilder, UnitStatistic, UnitStatistics, Weapon};
+use crate::common::{FACTOR_ATTACK, FACTOR_MAGIC, FACTOR_SKILL, FACTOR_WAIT, ID, Objective, Scene, SceneError, Target};
+use crate::dynamic::{Appliable, AppliableKind, Applier, Dynamic, Modifier, StatisticKind};
+use crate::event::{Condition, Consequence, Handler, Message, Observer, Response, Script, ScriptObserver, Subscriber, Subsystem};
+use crate::map::{Area, Direction, DIRECTIONS, Flank, Grid, Location, Mover, Positioning, Reachability, Search, Terrain, ThreatMap, TileBuilder, Weather, WeatherKind};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::cmp::Reverse;
 use std::collections::{BinaryHeap, HashSet};
 use std::error::Error;
+use std::fmt::{self, Display, Formatter};
 use std::ops::ControlFlow::{Break, Continue};
 use std::rc::Rc;
-use std::sync::mpsc::Sender;
+use std::sync::mpsc::{self, Receiver, Sender};
+
+// Forks are speculative, so only games that aren't quiet print
+macro_rules! output {
+    ($game: expr, $($argument: tt)*) => {
+        if !$game.is_quiet {
+            println! ($($argument)*);
+        }
+    };
+}
+
+const FORECAST_WEATHER_TURNS: usize = 3;
+const RANGE_DEATH: u8 = 1;
+#[allow (clippy::inconsistent_digit_grouping)]
+const SHOCK_SUCCESSION: u16 = 10_0; // 10.0%
+const DIVISOR_EXPERIENCE_DAMAGE: u16 = 10; // HLT damage per experience
+const EXPERIENCE_KILL: u16 = 50;
+const EXPERIENCE_SKILL: u16 = 10;
+const EXPERIENCE_CAPTURE: u16 = 30;
 
 /*
  * Calculated from build.rs
@@ -34,6 +56,8 @@ fn get_delay (mov: u16, action: Action) -> u16 {
 
 #[derive (Debug)]
 #[derive (Clone, Copy)]
+#[derive (PartialEq)]
+#[derive (Deserialize, Serialize)]
 pub enum Action {
     Attack,
     Weapon,
@@ -44,6 +68,40 @@ pub enum Action {
 }
 
 #[derive (Debug)]
+#[derive (Clone, Copy)]
+#[derive (PartialEq)]
+pub enum GameOutcome {
+    Victory (ID), // faction, shared with its allies
+    Draw,
+}
+
+impl Display for GameOutcome {
+    fn fmt (&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            GameOutcome::Victory (faction_id) => write! (f, "Faction {} wins", faction_id),
+            GameOutcome::Draw => write! (f, "Draw"),
+        }
+    }
+}
+
+// Result of a single update
+#[derive (Debug)]
+#[derive (Clone, Copy)]
+#[derive (PartialEq)]
+pub enum Progress {
+    Ongoing,
+    TurnOver,
+    GameOver (GameOutcome),
+}
+
+impl Progress {
+    pub fn is_turn_over (&self) -> bool {
+        !matches! (self, Progress::Ongoing)
+    }
+}
+
+#[derive (Debug)]
+#[derive (Clone)]
 pub enum State {
     Idle,
     Move,
@@ -109,6 +167,7 @@ pub enum Context<'a> {
 }
 
 #[derive (Debug)]
+#[derive (Clone)]
 pub struct Game {
     scene: Rc<Scene>,
     state: State,
@@ -119,6 +178,11 @@ pub struct Game {
     grid: Grid,
     units: Vec<Unit>,
     factions: Vec<Faction>,
+    holds: Vec<Option<usize>>, // turn each Hold objective started being held
+    weather_id: Option<ID>,
+    handler: Rc<Handler>, // never changes after creation, so forks can share it
+    scripts_fired: Vec<bool>,
+    is_quiet: bool,
     // Action context
     action: Action,
     location: Location,
@@ -145,9 +209,6 @@ impl Game {
         let turn: Option<Turn> = None;
         let turns: BinaryHeap<Turn> = BinaryHeap::new ();
         let number_turns: usize = 0;
-        // let handler: Handler = Handler::new ();
-        // let handler: RefCell<Handler> = RefCell::new (handler);
-        // let handler: Rc<RefCell<Handler>> = Rc::new (handler);
         let grid: Grid = Grid::new (Rc::clone (&scene));
         let units: Vec<Unit> = scene.unit_builders_iter ().map (|u: &UnitBuilder|
             u.build (Rc::clone (&scene))
@@ -155,6 +216,12 @@ impl Game {
         let factions: Vec<Faction> = scene.faction_builders_iter ().map (|f: &FactionBuilder|
             f.build (&units)
         ).collect ();
+        let holds: Vec<Option<usize>> = vec![None; scene.get_objectives ().len ()];
+        let weather_id: Option<ID> = None;
+        let handler: Handler = Game::build_handler (&scene);
+        let handler: Rc<Handler> = Rc::new (handler);
+        let scripts_fired: Vec<bool> = vec![false; scene.scripts_iter ().count ()];
+        let is_quiet: bool = false;
         let action: Action = Action::Wait;
         let location: Location = (usize::MAX, usize::MAX);
         let movements: Vec<Direction> = Vec::new ();
@@ -174,12 +241,45 @@ impl Game {
 
         let _ = sender.send (String::from ("Game creation complete"));
 
-        Self { scene, state, sender, turn, turns, number_turns, grid, units, factions, action, location, movements, mov, target, area, range, target_idx, target_location, potential_ids, potential_locations, target_ids, target_locations, skill_magic_idx, skill_magic_ids, skill_magic_id }
+        Self { scene, state, sender, turn, turns, number_turns, grid, units, factions, holds, weather_id, handler, scripts_fired, is_quiet, action, location, movements, mov, target, area, range, target_idx, target_location, potential_ids, potential_locations, target_ids, target_locations, skill_magic_idx, skill_magic_ids, skill_magic_id }
+    }
+
+    fn build_handler (scene: &Scene) -> Handler {
+        let mut handler: Handler = Handler::new ();
+        let subsystems_death: [Subsystem; 5] = [Subsystem::Death, Subsystem::Leadership, Subsystem::Occupancy, Subsystem::TurnOrder, Subsystem::Logger];
+
+        for subsystem in subsystems_death {
+            Game::register_subscriber (&mut handler, subsystem);
+        }
+
+        for (script_id, script) in scene.scripts_iter ().enumerate () {
+            let condition: Condition = script.get_condition ();
+            let observer: ScriptObserver = ScriptObserver::new (script_id, condition);
+            let observer: Rc<RefCell<dyn Observer>> = Rc::new (RefCell::new (observer));
+            let observer_id: ID = handler.register (observer);
+
+            handler.subscribe (observer_id, condition.discriminant ());
+        }
+
+        // Victory comes after scripts, so a death's scripts run before its faction's elimination
+        Game::register_subscriber (&mut handler, Subsystem::Victory);
+
+        handler
+    }
+
+    fn register_subscriber (handler: &mut Handler, subsystem: Subsystem) {
+        let observer: Subscriber = Subscriber::new (subsystem);
+        let observer: Rc<RefCell<dyn Observer>> = Rc::new (RefCell::new (observer));
+        let observer_id: ID = handler.register (observer);
+
+        handler.subscribe (observer_id, Message::UnitDied (0).discriminant ());
     }
 
     pub fn init (&mut self) -> Result<(), Box<dyn Error>> {
         let mut unit_locations: Vec<(ID, Location)> = Vec::new ();
 
+        self.update_weather ();
+
         for (unit_id, location) in self.scene.unit_locations_iter ().enumerate () {
             if let Some (location) = location {
                 unit_locations.push ((unit_id, *location));
@@ -194,13 +294,15 @@ impl Game {
             self.send_passive (unit_id);
         }
 
+        self.update_visibilities ();
+
         let _ = self.sender.send (String::from ("Game initialisation complete"));
 
         Ok (())
     }
 
     // TODO: Maybe just pass Game into Renderer
-    pub fn get_render_context (&self) -> RenderContext {
+    pub fn get_render_context (&self, faction_id: Option<ID>) -> RenderContext {
         // TODO: Obviously this can be cached
         let mut terrains: Vec<Vec<ID>> = Vec::new ();
         let mut unit_locations: Vec<Option<Location>> = Vec::new ();
@@ -217,24 +319,58 @@ impl Game {
 
         for unit_id in 0 .. self.units.len () {
             let location: Option<Location> = self.grid.get_unit_location (&unit_id).copied ();
+            // Viewers only see enemies where they were last seen
+            let location: Option<Location> = match faction_id {
+                Some (faction_id) if !self.factions[faction_id].is_ally (&self.units[unit_id].get_faction_id ()) => {
+                    self.factions[faction_id].get_unit_location_seen (&unit_id).copied ()
+                }
+                _ => location,
+            };
 
             unit_locations.push (location);
         }
 
-        RenderContext::new (terrains, unit_locations)
+        let locations_visible: Option<HashSet<Location>> = faction_id.map (|f: ID|
+            self.factions[f].get_locations_visible ().clone ()
+        );
+
+        let (reachability, threat_map): (Option<Reachability>, Option<ThreatMap>) = match (&self.state, &self.turn) {
+            (State::Move, Some (turn)) => {
+                let unit_id: ID = turn.get_unit_id ();
+                let faction_id_turn: ID = self.units[unit_id].get_faction_id ();
+                let mover: Mover = self.find_mover (unit_id);
+
+                (Some (self.grid.find_reachable_from (Some (&mover), &self.location, self.mov)), Some (self.find_threats (faction_id_turn, faction_id)))
+            }
+            _ => (None, None),
+        };
+
+        RenderContext::new (terrains, unit_locations, reachability, threat_map, locations_visible)
     }
 
     fn apply_terrain (&mut self, unit_id: ID, terrain_id: ID, location: Location) {
         let modifier_terrain_id: Option<ID> = self.scene.get_terrain (&terrain_id).get_modifier_id ();
         let appliable: Option<Box<dyn Appliable>> = self.grid.try_yield_appliable (&location);
 
+        self.units[unit_id].set_terrain_id (terrain_id);
         self.units[unit_id].change_modifier_terrain (modifier_terrain_id);
+        self.apply_weather (unit_id, &location);
 
         if let Some (a) = appliable {
             self.units[unit_id].add_appliable (a);
         }
     }
 
+    fn apply_weather (&mut self, unit_id: ID, location: &Location) {
+        let modifier_weather_id: Option<ID> = if self.grid.is_sheltered (location) {
+            None
+        } else {
+            self.get_weather ().and_then (|w: &Weather| w.get_modifier_unit_id ())
+        };
+
+        self.units[unit_id].change_modifier_weather (modifier_weather_id);
+    }
+
     fn add_turn (&mut self, unit_id: ID) {
         let mov: u16 = self.units[unit_id].get_statistic (UnitStatistic::MOV).0;
         let delay: u16 = get_delay (mov, Action::Wait);
@@ -271,36 +407,81 @@ impl Game {
         let leader_id: ID = self.units[unit_id].get_leader_id ();
         let faction_id: ID = self.scene.get_unit_builder (&unit_id).get_faction_id ();
         let follower_ids: &HashSet<ID> = self.factions[faction_id].get_followers (&leader_id);
-        let skill_passive_id: ID = self.units[leader_id].get_skill_passive_id ()
-                .unwrap_or_else (|| panic! ("Passive not found for leader {}", leader_id));
 
-        for follower_id in follower_ids {
-            let distance: usize = self.grid.find_distance_between (follower_id, &leader_id);
+        // Followers left leading themselves may have no passive to send
+        if let Some (skill_passive_id) = self.units[leader_id].get_skill_passive_id () {
+            for follower_id in follower_ids {
+                let distance: usize = self.grid.find_distance_between (follower_id, &leader_id);
 
-            self.units[*follower_id].try_add_passive (&skill_passive_id, distance);
+                self.units[*follower_id].try_add_passive (&skill_passive_id, distance);
+            }
         }
     }
 
     fn move_unit (&mut self, unit_id: ID) -> Location {
+        let city_factions: Vec<(ID, ID)> = self.find_city_factions ();
+        let mover: Mover = self.find_mover (unit_id);
         let (location, terrain_id): (Location, ID) = self.grid
-                .move_unit (unit_id, &self.movements)
+                .move_unit (&mover, &self.movements)
                 .unwrap_or_else (|| panic! ("Invalid movements {:?}", self.movements));
 
+        if let Some (facing) = self.movements.last () {
+            self.units[unit_id].set_facing (*facing);
+        }
+
         self.apply_terrain (unit_id, terrain_id, location);
         self.try_spawn_recruit (unit_id);
+        self.update_visibilities ();
+        self.notify (Message::UnitMoved (unit_id, location));
+
+        for ((city_id, faction_before), (_, faction_after)) in city_factions.into_iter ().zip (self.find_city_factions ()) {
+            if faction_before != faction_after {
+                if faction_after == self.units[unit_id].get_faction_id () {
+                    self.gain_experience (unit_id, EXPERIENCE_CAPTURE);
+                }
+
+                self.notify (Message::CityCaptured (city_id, faction_after));
+            }
+        }
 
         location
     }
 
     
-    fn attack_unit (&mut self, attacker_id: ID) {
+    // Returns the defenders who counterattacked
+    fn attack_unit (&mut self, attacker_id: ID) -> Vec<ID> {
+        let retaliation: Retaliation = *self.scene.get_retaliation ();
+        let mut retaliator_ids: Vec<ID> = Vec::new ();
+
+        if let RetaliationOrder::Before = retaliation.get_order () {
+            retaliator_ids = self.retaliate (attacker_id, &retaliation);
+        }
+
+        if self.units[attacker_id].is_alive () {
+            self.strike_units (attacker_id);
+        }
+
+        if let RetaliationOrder::After = retaliation.get_order () {
+            retaliator_ids = self.retaliate (attacker_id, &retaliation);
+        }
+
+        retaliator_ids
+    }
+
+    fn strike_units (&mut self, attacker_id: ID) {
         let statistics_attacker: &UnitStatistics = &self.units[attacker_id].get_statistics ();
+        let location_attacker: Location = *self.grid.get_unit_location (&attacker_id)
+                .unwrap_or_else (|| panic! ("Location not found for unit {}", attacker_id));
         let (_, weapon): (u16, &Weapon) = self.units[attacker_id].act_attack ();
         let weapon: Weapon = *weapon;
+        let scene: Rc<Scene> = Rc::clone (&self.scene);
+        let mut experience: u16 = 0;
 
         for defender_id in &self.target_ids {
             let statistics_defender: &UnitStatistics = &self.units[*defender_id].get_statistics ();
-            let (damage_mrl, damage_hlt, damage_spl): (u16, u16, u16) = UnitStatistics::calculate_damage (statistics_attacker, statistics_defender, &weapon);
+            let positioning: Positioning = self.find_positioning (attacker_id, &location_attacker, *defender_id);
+            let terrain_defender: &Terrain = scene.get_terrain (&self.find_unit_terrain_id (defender_id));
+            let (damage_mrl, damage_hlt, damage_spl): (u16, u16, u16) = UnitStatistics::calculate_damage (statistics_attacker, statistics_defender, &weapon, &positioning, terrain_defender);
             let appliable_on_attack: Option<Box<dyn Appliable>> = weapon.try_yield_appliable (Rc::clone (&self.scene));
             let appliable_on_hit: Option<Box<dyn Appliable>> = self.units[*defender_id].take_damage (damage_mrl, damage_hlt, damage_spl);
 
@@ -312,8 +493,70 @@ impl Game {
                 self.units[attacker_id].add_appliable (a);
             }
 
+            experience += damage_hlt / DIVISOR_EXPERIENCE_DAMAGE;
+
+            if !self.units[*defender_id].is_alive () {
+                experience += EXPERIENCE_KILL;
+            }
+
             // self.unit_ids_dirty.push (*defender_id);
         }
+
+        self.gain_experience (attacker_id, experience);
+    }
+
+    fn retaliate (&mut self, attacker_id: ID, retaliation: &Retaliation) -> Vec<ID> {
+        let defender_ids: Vec<ID> = self.target_ids.clone ();
+        let scene: Rc<Scene> = Rc::clone (&self.scene);
+        let terrain_attacker: &Terrain = scene.get_terrain (&self.find_unit_terrain_id (&attacker_id));
+        let mut retaliator_ids: Vec<ID> = Vec::new ();
+
+        for defender_id in defender_ids {
+            let defender: &Unit = &self.units[defender_id];
+            let weapon: Weapon = *defender.get_weapon ();
+            let distance: usize = self.grid.find_distance_between (&defender_id, &attacker_id);
+
+            if !self.units[attacker_id].is_alive () {
+                break
+            }
+
+            if !defender.is_alive () || defender.is_rout () || distance > (weapon.get_range () as usize) {
+                continue
+            }
+
+            let location_defender: Location = *self.grid.get_unit_location (&defender_id)
+                    .unwrap_or_else (|| panic! ("Location not found for unit {}", defender_id));
+            let statistics_defender: &UnitStatistics = &defender.get_statistics ();
+            let statistics_attacker: &UnitStatistics = &self.units[attacker_id].get_statistics ();
+            let positioning: Positioning = self.find_positioning (defender_id, &location_defender, attacker_id);
+            let (damage_mrl, damage_hlt, damage_spl): (u16, u16, u16) = UnitStatistics::calculate_damage (statistics_defender, statistics_attacker, &weapon, &positioning, terrain_attacker);
+            let damage_mrl: u16 = retaliation.scale_damage (damage_mrl);
+            let damage_hlt: u16 = retaliation.scale_damage (damage_hlt);
+            let damage_spl: u16 = retaliation.scale_damage (damage_spl);
+            let appliable_on_attack: Option<Box<dyn Appliable>> = weapon.try_yield_appliable (Rc::clone (&self.scene));
+            let appliable_on_hit: Option<Box<dyn Appliable>> = self.units[attacker_id].take_damage (damage_mrl, damage_hlt, damage_spl);
+
+            if let Some (a) = appliable_on_attack {
+                self.units[attacker_id].add_appliable (a);
+            }
+
+            if let Some (a) = appliable_on_hit {
+                self.units[defender_id].add_appliable (a);
+            }
+
+            let experience: u16 = damage_hlt / DIVISOR_EXPERIENCE_DAMAGE;
+            let experience: u16 = if self.units[attacker_id].is_alive () {
+                experience
+            } else {
+                experience + EXPERIENCE_KILL
+            };
+
+            self.gain_experience (defender_id, experience);
+            let _ = self.sender.send (format! ("{} counterattacks {}", defender_id, attacker_id));
+            retaliator_ids.push (defender_id);
+        }
+
+        retaliator_ids
     }
 
     fn use_skill_unit (&mut self, user_id: ID) {
@@ -329,49 +572,198 @@ impl Game {
             appliable_skill.set_applier_id (user_id);
             self.units[*target_id].add_appliable (appliable_skill);
         }
+
+        self.gain_experience (user_id, EXPERIENCE_SKILL);
+    }
+
+    // Only modifiers have a strength to scale
+    fn scale_appliable (&self, appliable: AppliableKind, multiplier: u16) -> Box<dyn Appliable> {
+        if let AppliableKind::Modifier ( .. ) = appliable {
+            let mut modifier: Modifier = appliable.modifier (Rc::clone (&self.scene));
+
+            modifier.scale (multiplier);
+
+            Box::new (modifier)
+        } else {
+            appliable.appliable (Rc::clone (&self.scene))
+        }
     }
 
-    fn use_magic_unit (&mut self, user_id: ID) {
-        let appliable_magic: AppliableKind = {
+    // Returns the units damaged by the magic
+    fn use_magic_unit (&mut self, user_id: ID) -> Vec<ID> {
+        let magic: Magic = {
             let (_, magic): (_, &Magic) = self.units[user_id].act_magic (&self.skill_magic_id);
-            let appliable_magic: AppliableKind = magic.get_appliable ();
 
-            appliable_magic
+            *magic
         };
+        let scene: Rc<Scene> = Rc::clone (&self.scene);
+        let element_matrix: &ElementMatrix = scene.get_element_matrix ();
+        let element: Element = magic.get_element ();
+        let mut damaged_ids: Vec<ID> = Vec::new ();
 
         match self.target {
             Target::This => {
-                let mut appliable_magic: Box<dyn Appliable> = appliable_magic.appliable (Rc::clone (&self.scene));
+                let affinity_terrain: Option<Element> = scene.get_terrain (&self.find_unit_terrain_id (&user_id)).get_affinity ();
+                let affinities: [Option<Element>; 2] = [self.units[user_id].get_affinity (), affinity_terrain];
+                let multiplier: u16 = element_matrix.scale (MULTIPLIER_BASE, element, &affinities);
+                let mut appliable_magic: Box<dyn Appliable> = self.scale_appliable (magic.get_appliable (), multiplier);
 
                 appliable_magic.set_applier_id (user_id);
                 self.units[user_id].add_appliable (appliable_magic);
             }
-            Target::Map => for target_location in &self.target_locations {
-                let mut appliable_magic: Box<dyn Appliable> = appliable_magic.appliable (Rc::clone (&self.scene));
+            Target::Map => {
+                let mut experience: u16 = 0;
+
+                for target_location in &self.target_locations {
+                    let terrain: &Terrain = scene.get_terrain (&self.grid.get_tile (target_location).get_terrain_id ());
+                    let affinity_terrain: Option<Element> = terrain.get_affinity ();
+                    let multiplier: u16 = element_matrix.scale (MULTIPLIER_BASE, element, &[affinity_terrain]);
+                    let mut appliable_magic: Box<dyn Appliable> = self.scale_appliable (magic.get_appliable (), multiplier);
+
+                    appliable_magic.set_applier_id (user_id);
+                    self.grid.add_appliable (target_location, appliable_magic);
+
+                    if let Some (unit_id) = self.grid.get_location_unit (target_location) {
+                        let appliable_on_occupy: Option<Box<dyn Appliable>> = self.grid.try_yield_appliable (target_location);
+                        let affinities: [Option<Element>; 2] = [self.units[*unit_id].get_affinity (), affinity_terrain];
+                        let damage: u16 = element_matrix.scale (magic.get_damage (), element, &affinities);
+                        let damage: u16 = terrain.scale_magic (damage);
+
+                        if let Some (appliable_on_occupy) = appliable_on_occupy {
+                            self.units[*unit_id].add_appliable (appliable_on_occupy);
+                        }
 
-                appliable_magic.set_applier_id (user_id);
-                self.grid.add_appliable (target_location, appliable_magic);
+                        if damage > 0 {
+                            if let Some (a) = self.units[*unit_id].take_damage (0, damage, 0) {
+                                self.units[user_id].add_appliable (a);
+                            }
+
+                            experience += damage / DIVISOR_EXPERIENCE_DAMAGE;
 
-                if let Some (unit_id) = self.grid.get_location_unit (target_location) {
-                    let appliable_on_occupy: Option<Box<dyn Appliable>> = self.grid.try_yield_appliable (target_location);
+                            if !self.units[*unit_id].is_alive () {
+                                experience += EXPERIENCE_KILL;
+                            }
 
-                    if let Some (appliable_on_occupy) = appliable_on_occupy {
-                        self.units[*unit_id].add_appliable (appliable_on_occupy);
+                            damaged_ids.push (*unit_id);
+                        }
                     }
                 }
+
+                self.gain_experience (user_id, experience);
             }
             _ => panic! ("Invalid target {:?}", self.target),
         }
+
+        damaged_ids
+    }
+
+    fn gain_experience (&mut self, unit_id: ID, experience: u16) {
+        if self.units[unit_id].gain_experience (experience) > 0 {
+            let _ = self.sender.send (format! ("{} reached level {}", unit_id, self.units[unit_id].get_level ()));
+            self.try_promote (unit_id);
+        }
+    }
+
+    // Promote to the first unlocked class
+    fn try_promote (&mut self, unit_id: ID) {
+        if let Some (class_id) = self.units[unit_id].find_promotions ().first () {
+            self.change_class (unit_id, *class_id);
+        }
+    }
+
+    fn change_class (&mut self, unit_id: ID, class_id: ID) {
+        self.units[unit_id].change_class (class_id);
+        let _ = self.sender.send (format! ("{} promoted to class {}", unit_id, class_id));
+    }
+
+    fn switch_weapon (&mut self, unit_id: ID) {
+        self.units[unit_id].switch_weapon ();
+        self.try_promote (unit_id);
     }
 
+    // Subscribers carry out the death, see build_handler for their order
     fn kill_unit (&mut self, unit_id: ID) {
-        // TODO: If player leader died, then end game and don't worry about all this
-        let faction_id: ID = self.scene.get_unit_builder (&unit_id).get_faction_id ();
-        let mut others: Vec<Turn> = Vec::new ();
+        self.notify (Message::UnitDied (unit_id));
+    }
+
+    fn kill_dead_units (&mut self, unit_ids: &[ID]) {
+        for unit_id in unit_ids {
+            if !self.units[*unit_id].is_alive () {
+                self.kill_unit (*unit_id);
+            }
+        }
+    }
+
+    fn apply_death (&mut self, unit_id: ID) {
+        if let Some (attribute) = self.units[unit_id].get_attribute_on_death () {
+            let location: Location = *self.grid.get_unit_location (&unit_id)
+                    .unwrap_or_else (|| panic! ("Location not found for unit {}", unit_id));
+            let faction_id: ID = self.units[unit_id].get_faction_id ();
+            let ally_ids: Vec<ID> = self.grid.find_units (&location, Search::Radial (RANGE_DEATH)).into_iter ().filter (|u: &ID|
+                *u != unit_id && self.factions[faction_id].is_ally (&self.units[*u].get_faction_id ())
+            ).collect ();
+
+            for ally_id in ally_ids {
+                if let Some (appliable) = attribute.try_yield_appliable (Rc::clone (&self.scene)) {
+                    self.units[ally_id].add_appliable (appliable);
+                }
+            }
+        }
+    }
+
+    fn find_successor (&self, unit_id: ID, follower_ids: &[ID]) -> Option<ID> {
+        let faction_id: ID = self.units[unit_id].get_faction_id ();
+        let follower_ids: Vec<ID> = follower_ids.iter ().copied ().filter (|f: &ID| self.units[*f].is_alive ()).collect ();
+        let heir_id: Option<ID> = self.scene.get_unit_builder (&unit_id).get_heir_id ()
+                .filter (|h: &ID| follower_ids.contains (h));
+
+        match self.factions[faction_id].get_succession () {
+            // Heirs who aren't surviving followers are passed over by organisation
+            Succession::Heir if heir_id.is_some () => heir_id,
+            Succession::Heir | Succession::Organisation => follower_ids.into_iter ().max_by_key (|f: &ID|
+                (self.units[*f].get_statistic (UnitStatistic::ORG).0, Reverse (*f))
+            ),
+            Succession::Nearest => follower_ids.into_iter ().min_by_key (|f: &ID|
+                (self.grid.find_distance_between (f, &unit_id), *f)
+            ),
+        }
+    }
+
+    fn reroute_followers (&mut self, unit_id: ID) {
+        let faction_id: ID = self.units[unit_id].get_faction_id ();
 
-        println! ("{} died", unit_id);
         self.factions[faction_id].remove_follower (&unit_id);
-        self.grid.remove_unit (&unit_id);
+
+        if self.units[unit_id].get_leader_id () == unit_id {
+            let follower_ids: Vec<ID> = self.factions[faction_id].get_followers (&unit_id).iter ()
+                    .copied ().filter (|f: &ID| *f != unit_id).collect ();
+            let successor_id: Option<ID> = self.find_successor (unit_id, &follower_ids);
+            let skill_passive_id: Option<ID> = self.units[unit_id].get_skill_passive_id ();
+            let follower_ids: Vec<ID> = self.factions[faction_id].reroute_followers (&unit_id, successor_id);
+
+            for follower_id in follower_ids {
+                // Followers lose the fallen leader's passive
+                if let Some (skill_passive_id) = skill_passive_id {
+                    self.units[follower_id].try_add_passive (&skill_passive_id, usize::MAX);
+                }
+
+                self.units[follower_id].set_leader_id (successor_id.unwrap_or (follower_id));
+                self.units[follower_id].lose_morale (SHOCK_SUCCESSION);
+            }
+
+            if let Some (successor_id) = successor_id {
+                // Successors keep their own passive, or else inherit the fallen leader's
+                let skill_passive_id: Option<ID> = self.scene.get_unit_builder (&successor_id).get_skill_passive_id ()
+                        .or (skill_passive_id);
+
+                self.units[successor_id].promote (skill_passive_id);
+                self.send_passive (successor_id);
+            }
+        }
+    }
+
+    fn remove_turn (&mut self, unit_id: ID) {
+        let mut others: Vec<Turn> = Vec::new ();
 
         while let Some (t) = self.turns.pop () {
             if t.get_unit_id () == unit_id {
@@ -384,7 +776,57 @@ impl Game {
         self.turns.extend (others);
     }
 
-    fn filter_unit_allegiance (&self, unit_ids: &[ID], faction_id: ID, is_ally: bool) -> Vec<ID> {
+    fn check_elimination (&mut self, unit_id: ID) {
+        let faction_id: ID = self.units[unit_id].get_faction_id ();
+
+        if !self.find_units_alive ().iter ().any (|u: &ID| self.units[*u].get_faction_id () == faction_id) {
+            self.notify (Message::FactionEliminated (faction_id));
+        }
+    }
+
+    pub fn find_unit_terrain_id (&self, unit_id: &ID) -> ID {
+        let location: &Location = self.grid.get_unit_location (unit_id)
+                .unwrap_or_else (|| panic! ("Location not found for unit {}", unit_id));
+
+        self.grid.get_tile (location).get_terrain_id ()
+    }
+
+    // Movement depends on the unit's current class and skills, and its faction's current allies
+    pub fn find_mover (&self, unit_id: ID) -> Mover {
+        let unit: &Unit = &self.units[unit_id];
+        let mut skill_ids: Vec<ID> = unit.get_skill_ids ();
+
+        skill_ids.extend (unit.get_skill_passive_id ());
+
+        Mover::new (unit_id, unit.get_class_id (), self.factions[unit.get_faction_id ()].get_allies (), &skill_ids)
+    }
+
+    // The attacker might not be at its location yet, as the computer considers attacks after moving
+    pub fn find_positioning (&self, attacker_id: ID, location_attacker: &Location, defender_id: ID) -> Positioning {
+        let location_defender: Location = *self.grid.get_unit_location (&defender_id)
+                .unwrap_or_else (|| panic! ("Location not found for unit {}", defender_id));
+        let height_attacker: i16 = self.grid.get_tile (location_attacker).get_height () as i16;
+        let height_defender: i16 = self.grid.get_tile (&location_defender).get_height () as i16;
+        let flank: Flank = Flank::find (self.units[defender_id].get_facing (), &location_defender, location_attacker);
+        let faction_id: ID = self.units[defender_id].get_faction_id ();
+        let surround: usize = DIRECTIONS.iter ().filter (|d: &&Direction| {
+            if let Some (location) = self.grid.try_connect (&location_defender, **d) {
+                if location == *location_attacker {
+                    true
+                } else if let Some (unit_id) = self.grid.get_location_unit (&location) {
+                    *unit_id != attacker_id && !self.factions[faction_id].is_ally (&self.units[*unit_id].get_faction_id ())
+                } else {
+                    false
+                }
+            } else {
+                false
+            }
+        }).count ();
+
+        Positioning::new (height_attacker - height_defender, flank, surround as u8)
+    }
+
+    pub fn filter_unit_allegiance (&self, unit_ids: &[ID], faction_id: ID, is_ally: bool) -> Vec<ID> {
         unit_ids.iter ().filter_map (|u: &ID| {
             let faction_id_other: ID = self.units[*u].get_faction_id ();
 
@@ -494,14 +936,19 @@ impl Game {
         }
     }
 
-    fn find_units_range (&self, unit_id: ID, target: Target, area: Area, range: u8) -> Vec<ID> {
+    pub fn find_units_range (&self, unit_id: ID, target: Target, area: Area, range: u8) -> Vec<ID> {
+        let location: &Location = self.grid.get_unit_location (&unit_id)
+                .unwrap_or_else (|| panic! ("Location not found for unit {}", unit_id));
+
+        self.find_units_range_from (unit_id, location, target, area, range)
+    }
+
+    pub fn find_units_range_from (&self, unit_id: ID, location: &Location, target: Target, area: Area, range: u8) -> Vec<ID> {
         if let Target::Map = target {
             panic! ("Invalid target {:?}", target)
         } else if let Target::This = target {
             vec![unit_id]
         } else {
-            let location: &Location = self.grid.get_unit_location (&unit_id)
-                    .unwrap_or_else (|| panic! ("Location not found for unit {}", unit_id));
             let neighbour_ids: Vec<ID> = if let Area::Path (w) = area {
                 let neighbour_ids_up: Vec<ID> = self.grid.find_units (location, Search::Path (w, range, Direction::Up));
                 let neighbour_ids_right: Vec<ID> = self.grid.find_units (location, Search::Path (w, range, Direction::Right));
@@ -514,7 +961,12 @@ impl Game {
                 neighbour_ids.extend (neighbour_ids_left.iter ());
                 neighbour_ids.extend (neighbour_ids_down.iter ());
 
-                neighbour_ids.into_iter ().collect ()
+                let mut neighbour_ids: Vec<ID> = neighbour_ids.into_iter ().collect ();
+
+                // Sorted for determinism
+                neighbour_ids.sort_unstable ();
+
+                neighbour_ids
             } else {
                 self.grid.find_units (location, Search::Radial (range))
             };
@@ -522,12 +974,59 @@ impl Game {
 
             match target {
                 Target::Ally | Target::Allies => self.filter_unit_allegiance (&neighbour_ids, faction_id, true),
-                Target::Enemy | Target::Enemies => self.filter_unit_allegiance (&neighbour_ids, faction_id, false),
+                Target::Enemy | Target::Enemies => {
+                    // Enemies hidden by fog can't be targeted, though location might reveal them
+                    let locations_visible: Vec<Location> = self.grid.find_locations_visible (location, self.units[unit_id].get_sight ());
+
+                    self.filter_unit_allegiance (&neighbour_ids, faction_id, false).into_iter ().filter (|e: &ID| {
+                        let location_enemy: &Location = self.grid.get_unit_location (e)
+                                .unwrap_or_else (|| panic! ("Location not found for unit {}", e));
+
+                        self.is_visible (faction_id, location_enemy) || locations_visible.contains (location_enemy)
+                    }).collect ()
+                }
                 _ => panic! ("Invalid target {:?}", target),
             }
         }
     }
 
+    // Viewers only know of the threats posed by enemies they can see
+    pub fn find_threats (&self, faction_id: ID, viewer_id: Option<ID>) -> ThreatMap {
+        let tile_builders: &[Vec<TileBuilder>] = self.scene.get_tile_builders ();
+        let mut threat_map: ThreatMap = ThreatMap::new (tile_builders.len (), tile_builders[0].len ());
+        let enemy_ids: Vec<ID> = self.filter_unit_allegiance (&self.find_units_alive (), faction_id, false).into_iter ().filter (|e: &ID|
+            viewer_id.is_none_or (|v: ID| {
+                let location: &Location = self.grid.get_unit_location (e)
+                        .unwrap_or_else (|| panic! ("Location not found for unit {}", e));
+
+                self.is_visible (v, location)
+            })
+        ).collect ();
+
+        for enemy_id in enemy_ids {
+            let enemy: &Unit = &self.units[enemy_id];
+            let weapon: &Weapon = enemy.get_weapon ();
+            let mov: u16 = enemy.get_statistic (UnitStatistic::MOV).0;
+            let mut strikes: Vec<(Area, u8)> = Vec::new ();
+
+            if let Target::Enemy | Target::Enemies = weapon.get_target () {
+                strikes.push ((weapon.get_area (), weapon.get_range ()));
+            }
+
+            for magic_id in enemy.get_magic_ids () {
+                let magic: &Magic = self.scene.get_magic (magic_id);
+
+                if let Target::Map = magic.get_target () {
+                    strikes.push ((magic.get_area (), magic.get_range ()));
+                }
+            }
+
+            self.grid.find_unit_threats (&mut threat_map, &self.find_mover (enemy_id), mov, &strikes);
+        }
+
+        threat_map
+    }
+
     fn find_locations_area (&self, search: Search) -> Vec<Location> {
         match search {
             Search::Single => vec![self.target_location],
@@ -551,7 +1050,12 @@ impl Game {
             neighbour_locations.extend (neighbour_locations_left.iter ());
             neighbour_locations.extend (neighbour_locations_down.iter ());
 
-            neighbour_locations.into_iter ().collect ()
+            let mut neighbour_locations: Vec<Location> = neighbour_locations.into_iter ().collect ();
+
+            // Sorted for determinism
+            neighbour_locations.sort_unstable ();
+
+            neighbour_locations
         } else {
             self.grid.find_locations (location, Search::Radial (range))
         }
@@ -568,90 +1072,494 @@ impl Game {
         }
     }
 
-    fn wait_unit (&mut self, unit_id: ID) -> u16 {
-        self.units[unit_id].act_wait ()
+    fn wait_unit (&mut self, unit_id: ID) -> u16 {
+        self.units[unit_id].act_wait ()
+    }
+
+    fn end_turn (&mut self, unit_id: ID) {
+        let city_ids: Vec<ID> = self.grid.find_unit_cities (&unit_id);
+        let location: Location = *self.grid.get_unit_location (&unit_id)
+                .unwrap_or_else (|| panic! ("Location not found for unit {}", unit_id));
+        let appliable: Option<Box<dyn Appliable>> = self.grid.try_yield_appliable (&location);
+
+        self.units[unit_id].end_turn (&city_ids, appliable);
+        self.grid.decrement_durations (&unit_id);
+        self.grid.expand_control (&unit_id);
+    }
+
+    fn update_turns (&mut self, mut turn: Turn, delay: u16, mov: u16) {
+        self.number_turns += 1;
+
+        if turn.update (delay, mov) {
+            self.turns.push (turn);
+        } else {
+            let reduction: u16 = turn.get_delay ();
+            let turns: Vec<Turn> = self.turns.drain ().collect ();
+
+            for mut turn in turns {
+                turn.reduce_delay (reduction);
+                self.turns.push (turn);
+            }
+
+            turn.reduce_delay (reduction);
+            turn.update (delay, mov);
+            self.turns.push (turn);
+        }
+    }
+
+    pub fn find_units_alive (&self) -> Vec<ID> {
+        (0 .. self.units.len ()).filter (|u: &ID|
+            self.grid.get_unit_location (u).is_some ()
+        ).collect ()
+    }
+
+    fn find_city_locations (&self, city_ids: &[ID]) -> Vec<Location> {
+        let mut locations: Vec<Location> = Vec::new ();
+
+        for (i, row) in self.scene.get_tile_builders ().iter ().enumerate () {
+            for (j, tile_builder) in row.iter ().enumerate () {
+                if tile_builder.get_city_id ().is_some_and (|c: ID| city_ids.contains (&c)) {
+                    locations.push ((i, j));
+                }
+            }
+        }
+
+        locations
+    }
+
+    fn find_city_factions (&self) -> Vec<(ID, ID)> {
+        let mut city_factions: Vec<(ID, ID)> = Vec::new ();
+
+        for (i, row) in self.scene.get_tile_builders ().iter ().enumerate () {
+            for (j, tile_builder) in row.iter ().enumerate () {
+                if let Some (city_id) = tile_builder.get_city_id () {
+                    city_factions.push ((city_id, *self.grid.get_location_faction (&(i, j))));
+                }
+            }
+        }
+
+        city_factions
+    }
+
+    fn notify (&mut self, message: Message) {
+        let handler: Rc<Handler> = Rc::clone (&self.handler);
+
+        for response in handler.notify (message) {
+            self.respond (response);
+        }
+    }
+
+    fn respond (&mut self, response: Response) {
+        match response {
+            Response::Script (script_id) => self.run_script (script_id),
+            Response::ApplyDeath (unit_id) => self.apply_death (unit_id),
+            Response::RerouteFollowers (unit_id) => self.reroute_followers (unit_id),
+            Response::RemoveUnit (unit_id) => {
+                self.grid.remove_unit (&unit_id);
+                self.update_visibilities ();
+            }
+            Response::RemoveTurn (unit_id) => self.remove_turn (unit_id),
+            Response::Log (message) => {
+                let _ = self.sender.send (message);
+            }
+            Response::CheckElimination (unit_id) => self.check_elimination (unit_id),
+            Response::TestAdd ( .. ) | Response::TestSubtract ( .. ) => (),
+        }
+    }
+
+    fn run_script (&mut self, script_id: ID) {
+        let scene: Rc<Scene> = Rc::clone (&self.scene);
+        let script: &Script = scene.get_script (&script_id);
+
+        if self.scripts_fired[script_id] && !script.is_repeatable () {
+            return
+        }
+
+        self.scripts_fired[script_id] = true;
+
+        for consequence in script.get_consequences () {
+            match consequence {
+                Consequence::Spawn (unit_id, location) => if self.grid.get_unit_location (unit_id).is_none () && self.units[*unit_id].is_alive () {
+                    if let Some (location) = self.grid.find_nearest_placeable (location) {
+                        output! (self, "{} arrives at {:?}", unit_id, location);
+                        self.place_unit (*unit_id, location);
+                        self.update_visibilities ();
+                    } else {
+                        let _ = self.sender.send (format! ("{} cannot arrive near {:?}", unit_id, location));
+                    }
+                }
+                Consequence::Ally (faction_id_first, faction_id_second, is_ally) => {
+                    self.factions[*faction_id_first].set_ally (*faction_id_second, *is_ally);
+                    self.factions[*faction_id_second].set_ally (*faction_id_first, *is_ally);
+                    self.update_visibilities ();
+                }
+                Consequence::Modify (modifier_id, location, radius) => {
+                    let modifier: &Modifier = scene.get_modifier (modifier_id);
+                    let locations: Vec<Location> = self.grid.find_locations (location, Search::Radial (*radius));
+
+                    for location in locations {
+                        let appliable: Box<dyn Appliable> = Box::new (modifier.clone ());
+
+                        if let StatisticKind::Tile ( .. ) = modifier.get_adjustments ()[0].0 {
+                            self.grid.add_appliable (&location, appliable);
+                        } else if let Some (unit_id) = self.grid.get_location_unit (&location).copied () {
+                            self.units[unit_id].add_appliable (appliable);
+                        }
+                    }
+                }
+                Consequence::Dialogue (dialogue) => {
+                    let _ = self.sender.send (dialogue.clone ());
+                }
+            }
+        }
+    }
+
+    fn update_holds (&mut self) {
+        for (i, (faction_id, objective)) in self.scene.get_objectives ().iter ().enumerate () {
+            if let Objective::Hold (city_ids, _) = objective {
+                let locations: Vec<Location> = self.find_city_locations (city_ids);
+                let is_held: bool = locations.iter ().all (|l: &Location|
+                    self.grid.get_location_faction (l) == faction_id
+                );
+
+                self.holds[i] = if is_held {
+                    self.holds[i].or (Some (self.number_turns))
+                } else {
+                    None
+                };
+            }
+        }
+    }
+
+    fn update_weather (&mut self) {
+        let weather_id: Option<ID> = self.scene.find_weather_id (self.number_turns);
+
+        if weather_id != self.weather_id {
+            let modifier_weather: Option<Modifier> = weather_id
+                    .and_then (|w: ID| self.scene.get_weather (&w).get_modifier_tile_id ())
+                    .map (|m: ID| self.scene.get_modifier (&m).clone ());
+
+            self.weather_id = weather_id;
+            self.grid.set_weather (modifier_weather);
+
+            if let Some (weather) = self.get_weather () {
+                let _ = self.sender.send (format! ("Weather changes to {:?}", weather.get_kind ()));
+            }
+        }
+
+        for unit_id in self.find_units_alive () {
+            let location: Location = *self.grid.get_unit_location (&unit_id)
+                    .unwrap_or_else (|| panic! ("Location not found for unit {}", unit_id));
+
+            self.apply_weather (unit_id, &location);
+        }
+    }
+
+    pub fn get_weather (&self) -> Option<&Weather> {
+        self.weather_id.map (|w: ID| self.scene.get_weather (&w))
+    }
+
+    pub fn forecast_weather (&self, number_turns: usize) -> Vec<WeatherKind> {
+        (1 ..= number_turns).map (|n: usize|
+            self.scene.find_weather_id (self.number_turns + n)
+                    .map_or (WeatherKind::Clear, |w: ID| self.scene.get_weather (&w).get_kind ())
+        ).collect ()
+    }
+
+    fn update_visibilities (&mut self) {
+        let unit_ids: Vec<ID> = self.find_units_alive ();
+
+        for faction_id in 0 .. self.factions.len () {
+            let mut locations_visible: HashSet<Location> = HashSet::new ();
+            let mut enemy_locations: Vec<(ID, Location)> = Vec::new ();
+
+            for unit_id in unit_ids.iter () {
+                let location: Location = *self.grid.get_unit_location (unit_id)
+                        .unwrap_or_else (|| panic! ("Location not found for unit {}", unit_id));
+
+                if self.factions[faction_id].is_ally (&self.units[*unit_id].get_faction_id ()) {
+                    locations_visible.extend (self.grid.find_locations_visible (&location, self.units[*unit_id].get_sight ()));
+                } else {
+                    enemy_locations.push ((*unit_id, location));
+                }
+            }
+
+            self.factions[faction_id].update_visibility (locations_visible, &enemy_locations);
+        }
+    }
+
+    pub fn is_visible (&self, faction_id: ID, location: &Location) -> bool {
+        self.factions[faction_id].is_visible (location)
+    }
+
+    fn is_eliminated (&self, faction_id: ID) -> bool {
+        self.find_units_alive ().iter ().all (|u: &ID|
+            self.factions[faction_id].is_ally (&self.units[*u].get_faction_id ())
+        )
+    }
+
+    fn is_complete (&self, index: usize, faction_id: ID, objective: &Objective) -> bool {
+        let unit_ids: Vec<ID> = self.find_units_alive ();
+
+        match objective {
+            Objective::Eliminate => self.is_eliminated (faction_id),
+            Objective::Assassinate (unit_id) => !unit_ids.contains (unit_id),
+            Objective::Hold (_, turns) => self.holds[index].is_some_and (|h: usize|
+                self.number_turns - h >= *turns
+            ),
+            Objective::Survive (turns) => self.number_turns >= *turns && unit_ids.iter ().any (|u: &ID|
+                self.units[*u].get_faction_id () == faction_id
+            ),
+            Objective::Control (percent) => {
+                let tile_builders: &[Vec<TileBuilder>] = self.scene.get_tile_builders ();
+                let number_tiles: usize = tile_builders.len () * tile_builders[0].len ();
+                let number_controlled: usize = self.grid.get_faction_locations (&faction_id)
+                        .map_or (0, |l: &HashSet<Location>| l.len ());
+
+                number_controlled * (MULTIPLIER_BASE as usize) >= (*percent as usize) * number_tiles
+            }
+        }
+    }
+
+    pub fn find_outcome (&self) -> Option<GameOutcome> {
+        let unit_ids: Vec<ID> = self.find_units_alive ();
+        let unit_id: ID = match unit_ids.first () {
+            Some (unit_id) => *unit_id,
+            None => return Some (GameOutcome::Draw),
+        };
+
+        for (i, (faction_id, objective)) in self.scene.get_objectives ().iter ().enumerate () {
+            if self.is_complete (i, *faction_id, objective) {
+                return Some (GameOutcome::Victory (*faction_id))
+            }
+        }
+
+        // The last alliance standing wins regardless of objectives
+        let faction_id: ID = self.units[unit_id].get_faction_id ();
+
+        if self.is_eliminated (faction_id) {
+            Some (GameOutcome::Victory (faction_id))
+        } else {
+            None
+        }
+    }
+
+    pub fn is_over (&self) -> bool {
+        self.find_outcome ().is_some ()
+    }
+
+    pub fn get_scene (&self) -> &Scene {
+        &self.scene
+    }
+
+    pub fn get_unit (&self, unit_id: &ID) -> &Unit {
+        &self.units[*unit_id]
+    }
+
+    pub fn get_faction (&self, faction_id: &ID) -> &Faction {
+        &self.factions[*faction_id]
+    }
+
+    pub fn get_grid (&self) -> &Grid {
+        &self.grid
+    }
+
+    pub fn get_unit_id_active (&self) -> ID {
+        let turn: &Turn = self.turn.as_ref ().unwrap_or_else (|| self.turns.peek ().expect ("Turn not found"));
+
+        turn.get_unit_id ()
+    }
+
+    pub fn get_number_turns (&self) -> usize {
+        self.number_turns
+    }
+
+    // Quiet games only send to the log, which suits headless runs
+    pub fn set_quiet (&mut self, is_quiet: bool) {
+        self.is_quiet = is_quiet;
+    }
+
+    pub fn fork (&self) -> Self {
+        // Forks are for speculation, so their logs are discarded
+        let (sender, _): (Sender<String>, Receiver<String>) = mpsc::channel ();
+        let mut game: Game = self.clone ();
+
+        game.sender = sender;
+        game.is_quiet = true;
+
+        game
+    }
+
+    pub fn forecast_attack (&self, attacker_id: ID, target_ids: &[ID]) -> Forecast {
+        let mut game: Game = self.fork ();
+        let appliable_on_attack: Option<AppliableKind> = self.units[attacker_id].get_weapon ()
+                .try_yield_appliable (Rc::clone (&self.scene))
+                .map (|a: Box<dyn Appliable>| a.kind ());
+        let mut appliables_on_hit: Vec<AppliableKind> = target_ids.iter ().filter_map (|t: &ID|
+            self.units[*t].try_yield_appliable (Rc::clone (&self.scene))
+        ).map (|a: Box<dyn Appliable>| a.kind ()).collect ();
+
+        game.target_ids = target_ids.to_vec ();
+
+        let retaliator_ids: Vec<ID> = game.attack_unit (attacker_id);
+
+        // Counterattacks add the retaliators' weapon attributes to the attacker
+        appliables_on_hit.extend (retaliator_ids.iter ().filter_map (|r: &ID|
+            self.units[*r].get_weapon ().try_yield_appliable (Rc::clone (&self.scene))
+        ).map (|a: Box<dyn Appliable>| a.kind ()));
+
+        let user: UnitForecast = UnitForecast::new (&self.units[attacker_id], &game.units[attacker_id], appliables_on_hit);
+        let targets: Vec<UnitForecast> = target_ids.iter ().map (|t: &ID|
+            UnitForecast::new (&self.units[*t], &game.units[*t], appliable_on_attack.into_iter ().collect ())
+        ).collect ();
+        let location_attacker: Location = *self.grid.get_unit_location (&attacker_id)
+                .unwrap_or_else (|| panic! ("Location not found for unit {}", attacker_id));
+        let positionings: Vec<(ID, Positioning)> = target_ids.iter ().map (|t: &ID|
+            (*t, self.find_positioning (attacker_id, &location_attacker, *t))
+        ).collect ();
+
+        Forecast::new (user, targets, retaliator_ids, positionings)
+    }
+
+    pub fn forecast_skill (&self, user_id: ID, skill_id: ID, target_ids: &[ID]) -> Forecast {
+        let mut game: Game = self.fork ();
+        let appliable_skill: AppliableKind = {
+            let mut user: Unit = self.units[user_id].clone ();
+            let (_, skill): (_, &Skill) = user.act_skill (&skill_id);
+
+            skill.get_appliable ()
+        };
+
+        game.skill_magic_id = skill_id;
+        game.target_ids = target_ids.to_vec ();
+        game.use_skill_unit (user_id);
+
+        let user: UnitForecast = UnitForecast::new (&self.units[user_id], &game.units[user_id], Vec::new ());
+        let targets: Vec<UnitForecast> = target_ids.iter ().map (|t: &ID|
+            UnitForecast::new (&self.units[*t], &game.units[*t], vec![appliable_skill])
+        ).collect ();
+
+        Forecast::new (user, targets, Vec::new (), Vec::new ())
+    }
+
+    pub fn forecast_magic (&self, user_id: ID, magic_id: ID, target_locations: &[Location]) -> Forecast {
+        let mut game: Game = self.fork ();
+        let magic: &Magic = self.scene.get_magic (&magic_id);
+
+        game.skill_magic_id = magic_id;
+        game.target = magic.get_target ();
+        game.target_locations = target_locations.to_vec ();
+        game.use_magic_unit (user_id);
+
+        let (user, targets): (UnitForecast, Vec<UnitForecast>) = if let Target::This = magic.get_target () {
+            (UnitForecast::new (&self.units[user_id], &game.units[user_id], vec![magic.get_appliable ()]), Vec::new ())
+        } else {
+            let targets: Vec<UnitForecast> = target_locations.iter ().filter_map (|l: &Location| {
+                let unit_id: ID = *game.grid.get_location_unit (l)?;
+                let appliables: Vec<AppliableKind> = game.grid.try_yield_appliable (l)
+                        .map (|a: Box<dyn Appliable>| a.kind ())
+                        .into_iter ()
+                        .collect ();
+
+                Some (UnitForecast::new (&self.units[unit_id], &game.units[unit_id], appliables))
+            }).collect ();
+
+            (UnitForecast::new (&self.units[user_id], &game.units[user_id], Vec::new ()), targets)
+        };
+
+        Forecast::new (user, targets, Vec::new (), Vec::new ())
     }
 
-    fn end_turn (&mut self, unit_id: ID) {
-        let city_ids: Vec<ID> = self.grid.find_unit_cities (&unit_id);
-        let location: Location = *self.grid.get_unit_location (&unit_id)
-                .unwrap_or_else (|| panic! ("Location not found for unit {}", unit_id));
-        let appliable: Option<Box<dyn Appliable>> = self.grid.try_yield_appliable (&location);
+    pub fn save (&self) -> Save {
+        let scene: Scene = (*self.scene).clone ();
 
-        self.units[unit_id].end_turn (&city_ids, appliable);
-        self.grid.decrement_durations (&unit_id);
-        self.grid.expand_control (&unit_id);
+        Save::new (scene, self.turn.clone (), self.turns.clone (), self.number_turns, self.mov, self.grid.clone (), self.units.clone (), self.factions.clone (), self.holds.clone (), self.scripts_fired.clone ())
     }
 
-    fn update_turns (&mut self, mut turn: Turn, delay: u16, mov: u16) {
-        self.number_turns += 1;
+    // Saves can be edited by hand, so their scene is checked before anything is replaced
+    pub fn load (&mut self, save: &Save) -> Result<(), Vec<SceneError>> {
+        save.get_scene ().validate ()?;
 
-        if turn.update (delay, mov) {
-            self.turns.push (turn);
-        } else {
-            let reduction: u16 = turn.get_delay ();
-            let turns: Vec<Turn> = self.turns.drain ().collect ();
+        let scene: Rc<Scene> = Rc::new (save.get_scene ().clone ());
+        let mut grid: Grid = save.get_grid ().clone ();
+        let mut units: Vec<Unit> = save.get_units ().to_vec ();
 
-            for mut turn in turns {
-                turn.reduce_delay (reduction);
-                self.turns.push (turn);
-            }
+        grid.set_scene (Rc::clone (&scene));
+        grid.set_unit_id_passable (None);
 
-            turn.reduce_delay (reduction);
-            turn.update (delay, mov);
-            self.turns.push (turn);
+        for unit in units.iter_mut () {
+            unit.set_scene (Rc::clone (&scene));
         }
-    }
 
-    pub fn load_scene (&mut self) {
-        todo! ()
+        self.scene = scene;
+        self.state = State::Idle;
+        self.turn = save.get_turn ().cloned ();
+        self.turns = save.get_turns ().clone ();
+        self.number_turns = save.get_number_turns ();
+        self.mov = save.get_mov ();
+        self.grid = grid;
+        self.units = units;
+        self.factions = save.get_factions ().to_vec ();
+        self.holds = save.get_holds ().to_vec ();
+        self.holds.resize (self.scene.get_objectives ().len (), None);
+        self.handler = Rc::new (Game::build_handler (&self.scene));
+        self.scripts_fired = save.get_scripts_fired ().to_vec ();
+        self.scripts_fired.resize (self.scene.scripts_iter ().count (), false);
+        // Tiles and units keep their weather modifiers, so only the ID needs restoring
+        self.weather_id = self.scene.find_weather_id (self.number_turns);
+
+        let _ = self.sender.send (String::from ("Game load complete"));
+
+        Ok (())
     }
 
     pub fn display_turn (&self) {
         let turn: &Turn = self.turn.as_ref ().unwrap_or_else (|| self.turns.peek ().unwrap ());
         let unit_id: ID = turn.get_unit_id ();
 
-        println! ("{}'s turn", unit_id);
-        print! ("{}", self.grid);
-        println! ("Turn order: {:?}\n", self.turns);
-    }
-
-    pub fn display_prompt (&self) {
-        let prompt: &str = match &self.state {
-            State::Idle => ActionValidator::get_prompt (),
-            State::Move => MovementValidator::get_prompt (),
-            State::TargetAttack => match self.area {
-                Area::Single | Area::Radial ( .. ) => IndexValidator::get_prompt (),
-                Area::Path ( .. ) => DirectionValidator::get_prompt (),
-            }
-            State::ConfirmAttack => ConfirmationValidator::get_prompt (),
-            State::ChooseSkill => IndexValidator::get_prompt (),
-            State::TargetSkill => IndexValidator::get_prompt (),
-            State::ConfirmSkill => ConfirmationValidator::get_prompt (),
-            State::ChooseMagic => IndexValidator::get_prompt (),
-            State::TargetMagic => if self.potential_locations.is_empty () {
-                ConfirmationValidator::get_prompt ()
+        output! (self, "{}'s turn", unit_id);
+        if !self.is_quiet {
+            print! ("{}", self.grid);
+        }
+        output! (self, "Weather: {:?}, forecast: {:?}", self.get_weather ().map_or (WeatherKind::Clear, |w: &Weather| w.get_kind ()), self.forecast_weather (FORECAST_WEATHER_TURNS));
+        output! (self, "Turn order: {:?}\n", self.turns);
+    }
+
+    pub fn get_prompt (&self) -> Prompt {
+        match &self.state {
+            State::Idle => Prompt::Action,
+            State::Move => Prompt::Movement,
+            State::TargetAttack | State::TargetSkill => match self.area {
+                Area::Single | Area::Radial ( .. ) => Prompt::Index,
+                Area::Path ( .. ) => Prompt::Direction,
+            }
+            State::ConfirmAttack => Prompt::Confirmation,
+            State::ChooseSkill => Prompt::Index,
+            State::ConfirmSkill => Prompt::Confirmation,
+            State::ChooseMagic => Prompt::Index,
+            State::TargetMagic => if let Area::Path ( .. ) = self.area {
+                Prompt::Direction
+            } else if self.potential_locations.is_empty () {
+                Prompt::Confirmation
             } else {
-                MovementValidator::get_prompt ()
+                Prompt::Movement
             }
-            State::ConfirmMagic => ConfirmationValidator::get_prompt (),
-        };
-
-        println! ("{}", prompt);
+            State::ConfirmMagic => Prompt::Confirmation,
+        }
     }
 
     fn change_state (&mut self, context: Context) {
         match context {
             Context::Idle => {
                 self.state = State::Idle;
-                println! ("Idle");
+                output! (self, "Idle");
             }
             Context::Move { location } => {
                 self.state = State::Move;
                 self.location = location;
                 self.movements.clear ();
                 // mov is updated elsewhere
-                println! ("Move");
+                output! (self, "Move");
             }
             Context::TargetAttack { target, area, range, potential_ids } => {
                 self.state = State::TargetAttack;
@@ -661,20 +1569,20 @@ impl Game {
                 self.target_idx = 0;
                 self.potential_ids.clear ();
                 self.potential_ids.extend_from_slice (potential_ids);
-                println! ("Target (attack)");
+                output! (self, "Target (attack)");
             }
             Context::ConfirmAttack { target_ids } => {
                 self.state = State::ConfirmAttack;
                 self.target_ids.clear ();
                 self.target_ids.extend_from_slice (target_ids);
-                println! ("Confirm (attack)");
+                output! (self, "Confirm (attack)");
             }
             Context::ChooseSkill { skill_ids } => {
                 self.state = State::ChooseSkill;
                 self.skill_magic_idx = 0;
                 self.skill_magic_ids.clear ();
                 self.skill_magic_ids.extend_from_slice (skill_ids);
-                println! ("Choose (skill)");
+                output! (self, "Choose (skill)");
             }
             Context::TargetSkill { target, area, range, skill_id, potential_ids } => {
                 self.state = State::TargetSkill;
@@ -685,20 +1593,20 @@ impl Game {
                 self.target_idx = 0;
                 self.potential_ids.clear ();
                 self.potential_ids.extend_from_slice (potential_ids);
-                println! ("Target (skill)");
+                output! (self, "Target (skill)");
             }
             Context::ConfirmSkill { target_ids } => {
                 self.state = State::ConfirmSkill;
                 self.target_ids.clear ();
                 self.target_ids.extend_from_slice (target_ids);
-                println! ("Confirm (skill)");
+                output! (self, "Confirm (skill)");
             }
             Context::ChooseMagic { magic_ids } => {
                 self.state = State::ChooseMagic;
                 self.skill_magic_idx = 0;
                 self.skill_magic_ids.clear ();
                 self.skill_magic_ids.extend_from_slice (magic_ids);
-                println! ("Choose (magic)");
+                output! (self, "Choose (magic)");
             }
             Context::TargetMagic { target, area, range, magic_id, potential_id, potential_locations, target_location } => {
                 self.state = State::TargetMagic;
@@ -711,13 +1619,13 @@ impl Game {
                 self.potential_locations.clear ();
                 self.potential_locations.extend_from_slice (potential_locations);
                 self.target_location = target_location;
-                println! ("Target (magic)");
+                output! (self, "Target (magic)");
             }
             Context::ConfirmMagic { target_locations } => {
                 self.state = State::ConfirmMagic;
                 self.target_locations.clear ();
                 self.target_locations.extend_from_slice (target_locations);
-                println! ("Confirm (magic)");
+                output! (self, "Confirm (magic)");
             }
         }
     }
@@ -757,7 +1665,7 @@ impl Game {
         }
     }
 
-    fn act_idle (&mut self, input: Keycode, unit_id: ID) -> Option<Action> {
+    fn act_idle (&mut self, input: Command, unit_id: ID) -> Option<Action> {
         let is_retreat: bool = self.units[unit_id].is_retreat ();
         let is_rout: bool = self.units[unit_id].is_rout ();
 
@@ -769,11 +1677,11 @@ impl Game {
 
                         match action {
                             Action::Attack => {
-                                println! ("{}'s action: Attack", unit_id);
+                                output! (self, "{}'s action: Attack", unit_id);
                                 let _ = self.sender.send (format! ("{}'s action: Attack", unit_id));
 
                                 if is_retreat {
-                                    println! ("Unit cannot attack (is retreating)")
+                                    output! (self, "Unit cannot attack (is retreating)")
                                 } else {
                                     let (target, area, range): (Target, Area, u8) = {
                                         let weapon: &Weapon = self.units[unit_id].get_weapon ();
@@ -785,7 +1693,7 @@ impl Game {
                                     let _ = self.sender.send (format! ("Potential targets: {:?}", potential_ids));
 
                                     if potential_ids.is_empty () {
-                                        println! ("No available targets");
+                                        output! (self, "No available targets");
                                     } else {
                                         self.change_state (Context::TargetAttack {
                                             target,
@@ -793,43 +1701,43 @@ impl Game {
                                             range,
                                             potential_ids: &potential_ids,
                                         });
-                                        println! ("Potential targets: {:?}", self.potential_ids);
-                                        println! ("Equipped weapon: {:?}", self.units[unit_id].get_weapon ());
+                                        output! (self, "Potential targets: {:?}", self.potential_ids);
+                                        output! (self, "Equipped weapon: {:?}", self.units[unit_id].get_weapon ());
                                     }
                                 }
 
                                 None
                             }
                             Action::Weapon => {
-                                println! ("{}'s action: Switch weapon", unit_id);
+                                output! (self, "{}'s action: Switch weapon", unit_id);
                                 let _ = self.sender.send (format! ("{}'s action: Switch weapon", unit_id));
 
                                 if is_rout {
-                                    println! ("Unit cannot rearm (is routed)")
+                                    output! (self, "Unit cannot rearm (is routed)")
                                 } else {
-                                    self.units[unit_id].switch_weapon ();
+                                    self.switch_weapon (unit_id);
                                     let _ = self.sender.send (format! ("{}'s action: Switch weapon", unit_id));
-                                    println! ("New weapon: {:?}", self.units[unit_id].get_weapon ());
+                                    output! (self, "New weapon: {:?}", self.units[unit_id].get_weapon ());
                                 }
 
                                 None
                             }
                             Action::Skill => {
-                                println! ("{}'s action: Skill", unit_id);
+                                output! (self, "{}'s action: Skill", unit_id);
                                 let _ = self.sender.send (format! ("{}'s action: Skill", unit_id));
 
                                 if is_rout {
-                                    println! ("Unit cannot use skill (is routed)")
+                                    output! (self, "Unit cannot use skill (is routed)")
                                 } else {
                                     let skill_ids: Vec<ID> = self.units[unit_id].get_skill_ids_actionable ();
 
                                     if skill_ids.is_empty () {
-                                        println! ("No available skills");
+                                        output! (self, "No available skills");
                                     } else {
                                         self.change_state (Context::ChooseSkill {
                                             skill_ids: &skill_ids,
                                         });
-                                        println! ("Skills: {:?}", self.skill_magic_ids);
+                                        output! (self, "Skills: {:?}", self.skill_magic_ids);
                                     }
                                 }
 
@@ -839,25 +1747,24 @@ impl Game {
                                 let _ = self.sender.send (format! ("{}'s action: Magic", unit_id));
 
                                 if is_rout {
-                                    println! ("Unit cannot use magic (is routed)")
+                                    output! (self, "Unit cannot use magic (is routed)")
                                 } else {
                                     let magic_ids: Vec<ID> = self.units[unit_id].get_magic_ids ().to_vec ();
 
                                     if magic_ids.is_empty () {
-                                        println! ("No available magics");
+                                        output! (self, "No available magics");
                                     } else {
                                         self.change_state (Context::ChooseMagic {
                                             magic_ids: &magic_ids,
                                         });
-                                        println! ("Magics: {:?}", self.skill_magic_ids);
+                                        output! (self, "Magics: {:?}", self.skill_magic_ids);
                                     }
                                 }
 
                                 None
                             }
                             Action::Move => {
-                                println! ("{}'s action: Move", unit_id);
-                                println! ("Movable locations: {:?}", self.grid.find_unit_movable (&unit_id, self.mov));
+                                output! (self, "{}'s action: Move", unit_id);
                                 let _ = self.sender.send (format! ("{}'s action: Move", unit_id));
 
                                 self.change_state (Context::Move {
@@ -866,12 +1773,19 @@ impl Game {
                                     // mov is updated on turn change
                                 });
                                 self.grid.set_unit_id_passable (Some (unit_id));
-                                println! ("Current location: {:?}", self.location);
+
+                                let reachability: Reachability = self.grid.find_unit_reachable (&self.find_mover (unit_id), self.mov);
+                                let locations: Vec<(Location, u16)> = reachability.locations_iter ().map (|l: Location|
+                                    (l, reachability.get_mov_remaining (&l).unwrap_or_else (|| panic! ("Cost not found for location {:?}", l)))
+                                ).collect ();
+
+                                output! (self, "Movable locations (MOV remaining): {:?}", locations);
+                                output! (self, "Current location: {:?}", self.location);
 
                                 None
                             }
                             Action::Wait => {
-                                println! ("{}'s action: Wait", unit_id);
+                                output! (self, "{}'s action: Wait", unit_id);
                                 let _ = self.sender.send (format! ("{}'s action: Wait", unit_id));
                                 self.wait_unit (unit_id);
 
@@ -887,14 +1801,16 @@ impl Game {
                 }
             }
             Err (e) => {
-                println! ("{}", e);
+                output! (self, "{}", e);
 
                 None
             }
         }
     }
 
-    fn act_move (&mut self, input: Keycode, unit_id: ID) -> Option<Action> {
+    fn act_move (&mut self, input: Command, unit_id: ID) -> Option<Action> {
+        let mover: Mover = self.find_mover (unit_id);
+
         match MovementValidator.validate (input) {
             Ok (flow) => {
                 match flow {
@@ -902,34 +1818,49 @@ impl Game {
                         // self.grid.set_unit_id_passable (None);
                         self.change_state (Context::Idle);
                         self.move_unit (unit_id);
-                        println! ("{:?}", self.movements);
-                        println! ("{:?}, {} MOV remaining", self.grid.get_unit_location (&unit_id), self.mov);
-                        print! ("{}", self.grid);
+                        output! (self, "{:?}", self.movements);
+                        output! (self, "{:?}, {} MOV remaining", self.grid.get_unit_location (&unit_id), self.mov);
+
+                        if !self.is_quiet {
+                            print! ("{}", self.grid);
+                        }
+
                         let _ = self.sender.send (format! ("Movements: {:?}", self.movements));
                     } else {
                         self.revert_state ();
                     }
-                    Continue (direction) => if let Some ((end, cost)) = self.grid.try_move (&self.location, direction) {
-                        println! ("{:?}", direction);
+                    Continue (_) if !self.movements.is_empty () && self.grid.is_stopped (Some (&mover), &self.location) => {
+                        output! (self, "Stopped by zone of control");
+                    }
+                    Continue (Movement::Direction (direction)) => if let Some ((end, cost)) = self.grid.try_move (Some (&mover), &self.location, direction) {
+                        output! (self, "{:?}", direction);
                         if self.mov >= (cost as u16) {
                             self.location = end;
                             self.movements.push (direction);
                             self.mov -= cost as u16;
                         } else {
-                            println! ("Insufficient MOV");
+                            output! (self, "Insufficient MOV");
                         }
                     } else {
-                        println! ("Invalid direction {:?}", direction);
+                        output! (self, "Invalid direction {:?}", direction);
+                    }
+                    Continue (Movement::Location (destination)) => if let Some ((movements, cost)) = self.grid.find_path_from (Some (&mover), &self.location, &destination, self.mov) {
+                        output! (self, "{:?}, {} MOV", movements, cost);
+                        self.location = destination;
+                        self.movements.extend (movements);
+                        self.mov -= cost;
+                    } else {
+                        output! (self, "Unreachable location {:?}", destination);
                     }
                 }
             }
-            Err (e) => println! ("{}", e),
+            Err (e) => output! (self, "{}", e),
         }
 
         None
     }
 
-    fn act_choose (&mut self, input: Keycode, unit_id: ID) -> Option<Action> {
+    fn act_choose (&mut self, input: Command, unit_id: ID) -> Option<Action> {
         match IndexValidator::new (self.skill_magic_idx, self.skill_magic_ids.len ()).validate (input) {
             Ok (flow) => {
                 match flow {
@@ -958,7 +1889,7 @@ impl Game {
                                 let _ = self.sender.send (format! ("Potential targets: {:?}", potential_ids));
 
                                 if potential_ids.is_empty () {
-                                    println! ("No available targets");
+                                    output! (self, "No available targets");
                                 } else {
                                     self.change_state (Context::TargetSkill {
                                         target,
@@ -967,8 +1898,8 @@ impl Game {
                                         skill_id: skill_magic_id,
                                         potential_ids: &potential_ids,
                                     });
-                                    println! ("Potential targets: {:?}", self.potential_ids);
-                                    println! ("Chosen skill: {:?}", self.scene.get_skill (&skill_magic_id));
+                                    output! (self, "Potential targets: {:?}", self.potential_ids);
+                                    output! (self, "Chosen skill: {:?}", self.scene.get_skill (&skill_magic_id));
                                 }
                             }
                             Action::Magic => {
@@ -990,12 +1921,12 @@ impl Game {
                                 });
 
                                 if self.potential_locations.is_empty () {
-                                    println! ("Potential target: Self");
+                                    output! (self, "Potential target: Self");
                                 } else {
-                                    println! ("Potential targets: {:?}", self.potential_locations);
+                                    output! (self, "Potential targets: {:?}", self.potential_locations);
                                 }
 
-                                println! ("Chosen magic: {:?}", self.scene.get_magic (&skill_magic_id));
+                                output! (self, "Chosen magic: {:?}", self.scene.get_magic (&skill_magic_id));
                                 let _ = self.sender.send (format! ("Potential targets: {:?}", self.potential_locations));
                             }
                             _ => panic! ("Invalid action {:?}", self.action),
@@ -1006,22 +1937,22 @@ impl Game {
                     Continue (index) => self.skill_magic_idx = index,
                 }
             }
-            Err (e) => println! ("{}", e),
+            Err (e) => output! (self, "{}", e),
         }
 
-        println! ("{:?}: {}", self.action, self.skill_magic_ids[self.skill_magic_idx]);
+        output! (self, "{:?}: {}", self.action, self.skill_magic_ids[self.skill_magic_idx]);
 
         None
     }
 
-    fn act_target (&mut self, input: Keycode, unit_id: ID) -> Option<Action> {
+    fn act_target (&mut self, input: Command, unit_id: ID) -> Option<Action> {
         if let Target::Map = self.target {
             let search: Option<Search> = if let Area::Path (w) = self.area {
                 match DirectionValidator.validate (input) {
                     Ok (flow) => {
                         match flow {
                             Break (direction) => if let Some (direction) = direction {
-                                println! ("{:?}", direction);
+                                output! (self, "{:?}", direction);
 
                                 Some (Search::Path (w, self.range, direction))
                             } else {
@@ -1033,7 +1964,7 @@ impl Game {
                         }
                     }
                     Err (e) => {
-                        println! ("{}", e);
+                        output! (self, "{}", e);
 
                         None
                     }
@@ -1053,25 +1984,35 @@ impl Game {
 
                                 None
                             }
-                            Continue (direction) => if let Some (end) = self.grid.try_connect (&self.target_location, direction) {                                
+                            Continue (Movement::Direction (direction)) => if let Some (end) = self.grid.try_connect (&self.target_location, direction) {                                
                                 if self.potential_locations.contains (&end) {
                                     self.target_location = end;
-                                    println! ("Target: {:?}", self.target_location);
-                                    println! ("{:?}", direction);
+                                    output! (self, "Target: {:?}", self.target_location);
+                                    output! (self, "{:?}", direction);
                                 } else {
-                                    println! ("Invalid direction {:?}", direction);
+                                    output! (self, "Invalid direction {:?}", direction);
                                 }
 
                                 None
                             } else {
-                                println! ("Invalid direction {:?}", direction);
+                                output! (self, "Invalid direction {:?}", direction);
+
+                                None
+                            }
+                            Continue (Movement::Location (location)) => {
+                                if self.potential_locations.contains (&location) {
+                                    self.target_location = location;
+                                    output! (self, "Target: {:?}", self.target_location);
+                                } else {
+                                    output! (self, "Invalid location {:?}", location);
+                                }
 
                                 None
                             }
                         }
                     }
                     Err (e) => {
-                        println! ("{}", e);
+                        output! (self, "{}", e);
 
                         None
                     }
@@ -1082,7 +2023,7 @@ impl Game {
                 let target_locations: Vec<Location> = self.find_locations_area (search);
 
                 if target_locations.is_empty () {
-                    println! ("No available targets");
+                    output! (self, "No available targets");
                 } else {
                     if let Action::Magic = self.action {
                         self.change_state (Context::ConfirmMagic {
@@ -1092,7 +2033,8 @@ impl Game {
                         panic! ("Invalid action {:?}", self.action);
                     }
 
-                    println! ("Targets: {:?}", self.target_locations);
+                    output! (self, "Targets: {:?}", self.target_locations);
+                    output! (self, "{}", self.forecast_magic (unit_id, self.skill_magic_id, &self.target_locations));
                 }
             }
         } else {
@@ -1101,7 +2043,7 @@ impl Game {
                     Ok (flow) => {
                         match flow {
                             Break (direction) => if let Some (direction) = direction {
-                                println! ("{:?}", direction);
+                                output! (self, "{:?}", direction);
 
                                 Some (Search::Path (w, self.range, direction))
                             } else {
@@ -1113,7 +2055,7 @@ impl Game {
                         }
                     }
                     Err (e) => {
-                        println! ("{}", e);
+                        output! (self, "{}", e);
 
                         None
                     }
@@ -1124,7 +2066,7 @@ impl Game {
                     Ok (flow) => {
                         match flow {
                             Break (is_confirm) => if is_confirm {
-                                println! ("Target: Self");
+                                output! (self, "Target: Self");
 
                                 Some (Search::Single)
                             } else {
@@ -1136,7 +2078,7 @@ impl Game {
                         }
                     }
                     Err (e) => {
-                        println! ("{}", e);
+                        output! (self, "{}", e);
 
                         None
                     }
@@ -1160,14 +2102,14 @@ impl Game {
                             }
                             Continue (index) => {
                                 self.target_idx = index;
-                                println! ("Target: {:?}", self.potential_ids[self.target_idx]);
+                                output! (self, "Target: {:?}", self.potential_ids[self.target_idx]);
 
                                 None
                             }
                         }
                     }
                     Err (e) => {
-                        println! ("{}", e);
+                        output! (self, "{}", e);
 
                         None
                     }
@@ -1178,7 +2120,7 @@ impl Game {
                 let target_ids: Vec<ID> = self.find_units_area_new (unit_id, search);
 
                 if target_ids.is_empty () {
-                    println! ("No available targets");
+                    output! (self, "No available targets");
                 } else {
                     match self.action {
                         Action::Attack => self.change_state (Context::ConfirmAttack {
@@ -1193,7 +2135,14 @@ impl Game {
                         _ => panic! ("Invalid action {:?}", self.action),
                     }
 
-                    println! ("Targets: {:?}", self.target_ids);
+                    output! (self, "Targets: {:?}", self.target_ids);
+
+                    match self.action {
+                        Action::Attack => output! (self, "{}", self.forecast_attack (unit_id, &self.target_ids)),
+                        Action::Skill => output! (self, "{}", self.forecast_skill (unit_id, self.skill_magic_id, &self.target_ids)),
+                        Action::Magic => output! (self, "{}", self.forecast_magic (unit_id, self.skill_magic_id, &[])),
+                        _ => panic! ("Invalid action {:?}", self.action),
+                    }
                 }
             }
         }
@@ -1201,7 +2150,7 @@ impl Game {
         None
     }
 
-    fn act_confirm (&mut self, input: Keycode, unit_id: ID) -> Option<Action> {
+    fn act_confirm (&mut self, input: Command, unit_id: ID) -> Option<Action> {
         match ConfirmationValidator.validate (input) {
             Ok (flow) => {
                 match flow {
@@ -1210,52 +2159,55 @@ impl Game {
 
                         match self.action {
                             Action::Attack => {
-                                self.attack_unit (unit_id);
-                                println! ("Attacking {:?}", self.target_ids);
+                                let retaliator_ids: Vec<ID> = self.attack_unit (unit_id);
+
+                                output! (self, "Attacking {:?}", self.target_ids);
+
+                                if !retaliator_ids.is_empty () {
+                                    output! (self, "Counterattacked by {:?}", retaliator_ids);
+                                }
 
                                 for target_id in &self.target_ids {
-                                    println! ("{}: {}", target_id, self.units[*target_id].get_statistics ());
+                                    output! (self, "{}: {}", target_id, self.units[*target_id].get_statistics ());
                                 }
 
-                                println! ("Self: {}", self.units[unit_id].get_statistics ());
+                                output! (self, "Self: {}", self.units[unit_id].get_statistics ());
 
                                 let target_ids: Vec<ID> = self.target_ids.drain ( .. ).collect ();
 
-                                for target_id in target_ids {
-                                    if !self.units[target_id].is_alive () {
-                                        self.kill_unit (target_id);
-                                    }
-                                }
+                                self.kill_dead_units (&target_ids);
                             }
                             Action::Skill => {
                                 for target_id in &self.target_ids {
-                                    println! ("{}", self.units[*target_id]);
+                                    output! (self, "{}", self.units[*target_id]);
                                 }
 
                                 self.use_skill_unit (unit_id);
-                                println! ("Using skill {} on {:?}", self.skill_magic_id, self.target_ids);
+                                output! (self, "Using skill {} on {:?}", self.skill_magic_id, self.target_ids);
 
                                 for target_id in &self.target_ids {
-                                    println! ("{}", self.units[*target_id]);
+                                    output! (self, "{}", self.units[*target_id]);
                                 }
                             }
                             Action::Magic => {
                                 if self.target_locations.is_empty () {
-                                    println! ("{}", self.units[unit_id]);
+                                    output! (self, "{}", self.units[unit_id]);
                                 }
 
-                                self.use_magic_unit (unit_id);
+                                let damaged_ids: Vec<ID> = self.use_magic_unit (unit_id);
 
                                 if self.target_locations.is_empty () {
-                                    println! ("Using magic {} on {}", self.skill_magic_id, unit_id);
-                                    println! ("{}", self.units[unit_id]);
+                                    output! (self, "Using magic {} on {}", self.skill_magic_id, unit_id);
+                                    output! (self, "{}", self.units[unit_id]);
                                 } else {
-                                    println! ("Using magic {} on {:?}", self.skill_magic_id, self.target_locations);
+                                    output! (self, "Using magic {} on {:?}", self.skill_magic_id, self.target_locations);
                 
                                     for target_location in &self.target_locations {
-                                        println! ("{:?}: {}", target_location, self.grid.get_tile (target_location));
+                                        output! (self, "{:?}: {}", target_location, self.grid.get_tile (target_location));
                                     }
                                 }
+
+                                self.kill_dead_units (&damaged_ids);
                             }
                             _ => panic! ("Invalid action {:?}", self.action),
                         }
@@ -1270,14 +2222,113 @@ impl Game {
                 }
             }
             Err (e) => {
-                println! ("{}", e);
+                output! (self, "{}", e);
 
                 None
             }
         }
     }
 
-    pub fn update (&mut self, input: Keycode) -> bool {
+    // Fully specified attacks are checked the same way as prompted ones, then confirmed
+    fn act_attack (&mut self, unit_id: ID, target_id: ID, weapon_id: ID) -> Option<Action> {
+        if !matches! (self.state, State::Idle) {
+            output! (self, "Attack must be chosen from idle");
+
+            return None
+        }
+
+        if self.units[unit_id].is_retreat () {
+            output! (self, "Unit cannot attack (is retreating)");
+
+            return None
+        }
+
+        let weapon_ids: Vec<ID> = self.units[unit_id].get_weapon_ids ();
+
+        if !weapon_ids.contains (&weapon_id) {
+            output! (self, "Invalid weapon {}", weapon_id);
+
+            return None
+        }
+
+        if self.units[unit_id].get_weapon ().get_id () != weapon_id {
+            if self.units[unit_id].is_rout () {
+                output! (self, "Unit cannot rearm (is routed)");
+
+                return None
+            }
+
+            // Switching can promote the unit, which may drop the weapon
+            for _ in 0 .. weapon_ids.len () {
+                if self.units[unit_id].get_weapon ().get_id () == weapon_id {
+                    break
+                }
+
+                self.switch_weapon (unit_id);
+            }
+
+            if self.units[unit_id].get_weapon ().get_id () != weapon_id {
+                output! (self, "Invalid weapon {}", weapon_id);
+
+                return None
+            }
+        }
+
+        let (target, area, range): (Target, Area, u8) = {
+            let weapon: &Weapon = self.units[unit_id].get_weapon ();
+
+            (weapon.get_target (), weapon.get_area (), weapon.get_range ())
+        };
+        let potential_ids: Vec<ID> = self.find_units_range (unit_id, target, area, range);
+        let target_idx: usize = match potential_ids.iter ().position (|p: &ID| *p == target_id) {
+            Some (target_idx) => target_idx,
+            None => {
+                output! (self, "Invalid target {}", target_id);
+
+                return None
+            }
+        };
+
+        let _ = self.sender.send (format! ("{}'s action: Attack", unit_id));
+        self.action = Action::Attack;
+        self.change_state (Context::TargetAttack {
+            target,
+            area,
+            range,
+            potential_ids: &potential_ids,
+        });
+        self.target_idx = target_idx;
+
+        let search: Option<Search> = match area {
+            Area::Single => Some (Search::Single),
+            Area::Radial (r) => Some (Search::Radial (r)),
+            Area::Path (w) => DIRECTIONS.iter ().map (|d: &Direction| Search::Path (w, range, *d))
+                    .find (|s: &Search| self.find_units_area_new (unit_id, *s).contains (&target_id)),
+        };
+        let target_ids: Vec<ID> = match search {
+            Some (search) => self.find_units_area_new (unit_id, search),
+            None => Vec::new (),
+        };
+
+        if target_ids.is_empty () {
+            output! (self, "No available targets");
+            self.revert_state ();
+
+            return None
+        }
+
+        self.change_state (Context::ConfirmAttack {
+            target_ids: &target_ids,
+        });
+
+        self.act_confirm (Command::Confirm, unit_id)
+    }
+
+    pub fn update (&mut self, input: Command) -> Progress {
+        if let Some (outcome) = self.find_outcome () {
+            return Progress::GameOver (outcome)
+        }
+
         let unit_id: ID = if let Some (turn) = &self.turn {
             // println! ("Delay: {}", turn.get_delay ());
 
@@ -1301,24 +2352,28 @@ impl Game {
         // println! ("Turn order: {:?}\n", self.turns);
         // println! ("Actions: Move (q), switch weapon (w), attack (a), skill (s), magic (d), wait (z)");
 
-        let action: Option<Action> = match self.state {
-            State::Idle => self.act_idle (input, unit_id),
-            State::Move => self.act_move (input, unit_id),
-            State::TargetAttack => self.act_target (input, unit_id),
-            State::ConfirmAttack => self.act_confirm (input, unit_id),
-            State::ChooseSkill => self.act_choose (input, unit_id),
-            State::TargetSkill => self.act_target (input, unit_id),
-            State::ConfirmSkill => self.act_confirm (input, unit_id),
-            State::ChooseMagic => self.act_choose (input, unit_id),
-            State::TargetMagic => self.act_target (input, unit_id),
-            State::ConfirmMagic => self.act_confirm (input, unit_id),
+        let action: Option<Action> = if let Command::Attack (target_id, weapon_id) = input {
+            self.act_attack (unit_id, target_id, weapon_id)
+        } else {
+            match self.state {
+                State::Idle => self.act_idle (input, unit_id),
+                State::Move => self.act_move (input, unit_id),
+                State::TargetAttack => self.act_target (input, unit_id),
+                State::ConfirmAttack => self.act_confirm (input, unit_id),
+                State::ChooseSkill => self.act_choose (input, unit_id),
+                State::TargetSkill => self.act_target (input, unit_id),
+                State::ConfirmSkill => self.act_confirm (input, unit_id),
+                State::ChooseMagic => self.act_choose (input, unit_id),
+                State::TargetMagic => self.act_target (input, unit_id),
+                State::ConfirmMagic => self.act_confirm (input, unit_id),
+            }
         };
 
         if let Some (action) = action {
             let turn: Turn = self.turn.take ()
                     .expect ("Turn not found");
 
-            println! ("End {}'s turn\n", unit_id);
+            output! (self, "End {}'s turn\n", unit_id);
             let _ = self.sender.send (format! ("End {}'s turn", unit_id));
             self.send_passive (unit_id);
 
@@ -1334,13 +2389,25 @@ impl Game {
 
             if self.units[unit_id].is_alive () {
                 self.update_turns (turn, delay, mov);
+                self.notify (Message::TurnReached (self.number_turns));
             } else {
                 self.kill_unit (unit_id);
             }
 
-            true
+            self.update_holds ();
+            self.update_weather ();
+            self.update_visibilities ();
+
+            if let Some (outcome) = self.find_outcome () {
+                output! (self, "{}", outcome);
+                let _ = self.sender.send (outcome.to_string ());
+
+                Progress::GameOver (outcome)
+            } else {
+                Progress::TurnOver
+            }
         } else {
-            false
+            Progress::Ongoing
         }
     }
 }
@@ -1348,7 +2415,7 @@ impl Game {
 #[cfg (test)]
 mod tests {
     use super::*;
-    use UnitStatistic::{MRL, HLT, SPL, ATK, DEF, MAG, ORG};
+    use UnitStatistic::{MRL, HLT, SPL, ATK, DEF, MAG, MOV, ORG};
     use std::sync::mpsc;
 
     fn generate_game () -> Game {
@@ -1410,6 +2477,58 @@ mod tests {
         // -10% ATK from passive, +20% ATK from toggle, +20% ATK from terrain
         assert_eq! (game.units[0].get_statistic (ATK).0, 26);
         assert_eq! (game.grid.get_unit_location (&3).unwrap (), &(0, 0));
+        assert_eq! (game.units[0].get_facing (), Some (Direction::Left));
+    }
+
+    #[test]
+    fn game_find_mover () {
+        let mut game = generate_game ();
+
+        game.init ().unwrap ();
+
+        let mover = game.find_mover (0);
+
+        assert_eq! (mover.get_class_id (), 2);
+        assert! (mover.get_skill_ids ().contains (&1));
+        assert! (!mover.is_ally (&1));
+        // Test changed class
+        game.units[0].change_class (1);
+        assert_eq! (game.find_mover (0).get_class_id (), 1);
+        // Test changed skills
+        game.units[0].promote (Some (6));
+        assert! (game.find_mover (0).get_skill_ids ().contains (&6));
+        // Test changed allies
+        game.factions[0].set_ally (1, true);
+        assert! (game.find_mover (0).is_ally (&1));
+    }
+
+    #[test]
+    fn game_find_positioning () {
+        let mut game = generate_game ();
+
+        game.place_unit (0, (0, 0));
+        game.place_unit (2, (1, 0));
+        // Test height
+        let positioning = game.find_positioning (2, &(1, 0), 0);
+        assert_eq! (positioning.get_height (), 2);
+        assert_eq! (positioning.get_flank (), Flank::Front);
+        assert_eq! (positioning.get_surround (), 1);
+        assert_eq! (game.find_positioning (0, &(0, 0), 2).get_height (), -2);
+        // Test flank
+        game.units[0].set_facing (Direction::Right);
+        assert_eq! (game.find_positioning (2, &(1, 0), 0).get_flank (), Flank::Side);
+        game.units[0].set_facing (Direction::Up);
+        assert_eq! (game.find_positioning (2, &(1, 0), 0).get_flank (), Flank::Rear);
+        game.units[0].set_facing (Direction::Down);
+        assert_eq! (game.find_positioning (2, &(1, 0), 0).get_flank (), Flank::Front);
+        // Test hypothetical location
+        assert_eq! (game.find_positioning (0, &(1, 1), 2).get_surround (), 1);
+        // Test surround
+        game.place_unit (4, (1, 1));
+        let positioning = game.find_positioning (4, &(1, 1), 2);
+        assert_eq! (positioning.get_height (), -1);
+        assert_eq! (positioning.get_flank (), Flank::Front);
+        assert! (positioning.is_surrounded ());
     }
 
     #[test]
@@ -1441,6 +2560,71 @@ mod tests {
         // assert_eq! (game.units[2].get_statistic (MAG).0, 18);
     }
 
+    #[test]
+    fn game_retaliate () {
+        let mut game = generate_game ();
+
+        game.init ().unwrap ();
+        game.target_ids = vec![2];
+
+        let hlt_0_0 = game.units[0].get_statistic (HLT).0;
+        let mrl_0_0 = game.units[0].get_statistic (MRL).0;
+
+        // Test counterattack in range
+        assert_eq! (game.attack_unit (0), vec![2]);
+        assert! (game.units[0].get_statistic (HLT).0 < hlt_0_0);
+        assert! (game.units[0].get_statistic (MRL).0 < mrl_0_0);
+        // Test reduced effectiveness
+        let hlt_0_1 = game.units[0].get_statistic (HLT).0;
+        let statistics_0 = game.units[0].get_statistics ();
+        let statistics_2 = game.units[2].get_statistics ();
+        let positioning = game.find_positioning (2, &(1, 0), 0);
+        let terrain = game.scene.get_terrain (&game.find_unit_terrain_id (&0));
+        let (_, damage_hlt, _) = UnitStatistics::calculate_damage (&statistics_2, &statistics_0, game.units[2].get_weapon (), &positioning, terrain);
+        let retaliation = *game.scene.get_retaliation ();
+
+        game.retaliate (0, &retaliation);
+        assert_eq! (hlt_0_1 - game.units[0].get_statistic (HLT).0, damage_hlt / 2);
+        // Test routed defender
+        game.units[2].set_statistic (MRL, 100);
+        assert! (game.units[2].is_rout ());
+        assert! (game.attack_unit (0).is_empty ());
+    }
+
+    #[test]
+    fn game_retaliate_before () {
+        let after = format! ("retaliation:{}", ron::to_string (Scene::default ().get_retaliation ()).unwrap ());
+        let before = format! ("retaliation:{}", ron::to_string (&Retaliation::new (RetaliationOrder::Before, 50)).unwrap ());
+        let scene = ron::to_string (&Scene::default ()).unwrap ();
+        assert! (scene.contains (&after));
+        let scene: Scene = ron::from_str (&scene.replace (&after, &before)).unwrap ();
+        let (sender, _) = mpsc::channel ();
+        let mut game = Game::new (scene, sender);
+
+        game.init ().unwrap ();
+        game.target_ids = vec![2];
+
+        // Test defender strikes first
+        let hlt_0_0 = game.units[0].get_statistic (HLT).0;
+        let hlt_2_0 = game.units[2].get_statistic (HLT).0;
+        let statistics_0 = game.units[0].get_statistics ();
+        let statistics_2 = game.units[2].get_statistics ();
+        let positioning = game.find_positioning (2, &(1, 0), 0);
+        let terrain = game.scene.get_terrain (&game.find_unit_terrain_id (&0));
+        let (_, damage_hlt, _) = UnitStatistics::calculate_damage (&statistics_2, &statistics_0, game.units[2].get_weapon (), &positioning, terrain);
+
+        assert_eq! (game.attack_unit (0), vec![2]);
+        assert_eq! (hlt_0_0 - game.units[0].get_statistic (HLT).0, damage_hlt / 2);
+        assert! (game.units[2].get_statistic (HLT).0 < hlt_2_0);
+        // Test attacker killed before striking
+        let hlt_2_1 = game.units[2].get_statistic (HLT).0;
+
+        game.units[0].set_statistic (HLT, 1);
+        assert_eq! (game.attack_unit (0), vec![2]);
+        assert! (!game.units[0].is_alive ());
+        assert_eq! (game.units[2].get_statistic (HLT).0, hlt_2_1);
+    }
+
     #[test]
     fn game_use_skill_unit () {
         todo!();
@@ -1497,6 +2681,176 @@ mod tests {
         // assert_eq! (game.units[0].get_statistic (HLT).0, 940);
     }
 
+    #[test]
+    fn game_fork () {
+        let scene = Scene::default ();
+        let (sender, receiver) = mpsc::channel ();
+        let mut game = Game::new (scene, sender);
+
+        game.init ().unwrap ();
+        while receiver.try_recv ().is_ok () {}
+
+        let fork = game.fork ();
+
+        assert! (fork.is_quiet);
+        assert! (!game.is_quiet);
+        // Test forecasts produce no output
+        game.forecast_attack (0, &[2]);
+        game.forecast_skill (3, 6, &[3]);
+        game.forecast_magic (0, 0, &[]);
+        assert! (receiver.try_recv ().is_err ());
+    }
+
+    #[test]
+    fn game_set_quiet () {
+        let mut game = generate_game ();
+
+        assert! (!game.is_quiet);
+        game.set_quiet (true);
+        assert! (game.is_quiet);
+        game.set_quiet (false);
+        assert! (!game.is_quiet);
+    }
+
+    #[test]
+    fn game_forecast_attack () {
+        let mut game = generate_game ();
+        let attribute_9 = *game.scene.get_attribute (&9);
+        let attribute_9 = Box::new (attribute_9);
+        let attribute_10 = *game.scene.get_attribute (&10);
+        let attribute_10 = Box::new (attribute_10);
+
+        game.init ().unwrap ();
+        game.units[0].add_appliable (attribute_10);
+        game.units[2].add_appliable (attribute_9);
+
+        let forecast = game.forecast_attack (0, &[2]);
+        let mrl_2_0 = game.units[2].get_statistic (MRL).0;
+        let hlt_2_0 = game.units[2].get_statistic (HLT).0;
+        let spl_2_0 = game.units[2].get_statistic (SPL).0;
+        let spl_0_0 = game.units[0].get_statistic (SPL).0;
+        let target = forecast.get_target (&2).unwrap ();
+
+        // Test forecast is non-destructive
+        assert_eq! (game.units[2].get_statistic (MRL).0, mrl_2_0);
+        assert! (forecast.get_user ().get_loss (SPL) > 0);
+        assert! (target.get_loss (MRL) > 0);
+        assert! (target.get_loss (HLT) > 0);
+        assert! (target.get_loss (SPL) > 0);
+        assert! (target.is_alive ());
+        assert! (!target.is_retreat ());
+        assert! (!target.is_rout ());
+        assert_eq! (forecast.get_user ().get_appliables (), &[AppliableKind::Modifier (7)]);
+        assert_eq! (target.get_appliables (), &[AppliableKind::Modifier (8)]);
+        assert! (forecast.is_retaliator (&2));
+        assert_eq! (forecast.get_positioning (&2).unwrap ().get_height (), -2);
+        assert! (forecast.get_user ().get_loss (HLT) > 0);
+        // Test forecast matches attack
+        game.target_ids = vec![2];
+        game.attack_unit (0);
+        assert_eq! (mrl_2_0 - game.units[2].get_statistic (MRL).0, target.get_loss (MRL));
+        assert_eq! (hlt_2_0 - game.units[2].get_statistic (HLT).0, target.get_loss (HLT));
+        assert_eq! (spl_2_0 - game.units[2].get_statistic (SPL).0, target.get_loss (SPL));
+        assert_eq! (spl_0_0 - game.units[0].get_statistic (SPL).0, forecast.get_user ().get_loss (SPL));
+        // Test death
+        game.units[2].set_statistic (MRL, 1);
+        game.units[2].set_statistic (HLT, 1);
+
+        let forecast = game.forecast_attack (0, &[2]);
+
+        assert! (!forecast.get_target (&2).unwrap ().is_alive ());
+    }
+
+    #[test]
+    fn game_forecast_skill () {
+        let mut game = generate_game ();
+
+        game.init ().unwrap ();
+
+        let def_3_0 = game.units[3].get_statistic (DEF).0;
+        let forecast = game.forecast_skill (3, 6, &[3]);
+
+        assert! (forecast.get_user ().get_loss (SPL) > 0);
+        assert_eq! (forecast.get_target (&3).unwrap ().get_appliables (), &[game.scene.get_skill (&6).get_appliable ()]);
+        assert_eq! (game.units[3].get_statistic (DEF).0, def_3_0);
+    }
+
+    #[test]
+    fn game_use_magic_element () {
+        let mut game = generate_game ();
+
+        game.init ().unwrap ();
+
+        // Test resisted modifier
+        let atk_0_0 = game.units[0].get_statistic (ATK).0;
+        game.skill_magic_id = 0;
+        game.target = Target::This;
+        game.use_magic_unit (0);
+        let atk_0_1 = game.units[0].get_statistic (ATK).0;
+        assert_eq! (atk_0_1 - atk_0_0, atk_0_0 / 20);
+        // Test damage weakened by terrain
+        let hlt_2_0 = game.units[2].get_statistic (HLT).0;
+        let forecast_light = game.forecast_magic (0, 3, &[(1, 0)]);
+        let forecast_dark = game.forecast_magic (0, 4, &[(1, 0)]);
+        assert_eq! (game.units[2].get_statistic (HLT).0, hlt_2_0);
+        // Forest resists 20% of magic damage
+        assert_eq! (forecast_light.get_target (&2).unwrap ().get_loss (HLT) - forecast_dark.get_target (&2).unwrap ().get_loss (HLT), 16);
+        // Test damage experience
+        let experience_0_0 = game.units[0].get_experience ();
+        game.skill_magic_id = 3;
+        game.target = Target::Map;
+        game.target_locations = vec![(1, 0)];
+        assert_eq! (game.use_magic_unit (0), vec![2]);
+        let experience_0_1 = game.units[0].get_experience ();
+        assert! (experience_0_1 > experience_0_0);
+    }
+
+    #[test]
+    fn game_use_magic_lethal () {
+        let mut game = generate_game ();
+
+        game.init ().unwrap ();
+        game.units[2].set_statistic (HLT, 1);
+        game.skill_magic_id = 3;
+        game.target = Target::Map;
+        game.target_locations = vec![(1, 0)];
+
+        let experience_0_0 = game.units[0].get_experience ();
+        let damaged_ids = game.use_magic_unit (0);
+        let experience_0_1 = game.units[0].get_experience ();
+
+        assert_eq! (damaged_ids, vec![2]);
+        assert! (!game.units[2].is_alive ());
+        assert! (experience_0_1 - experience_0_0 >= EXPERIENCE_KILL);
+        // Test dead targets are killed
+        game.kill_dead_units (&damaged_ids);
+        assert! (game.grid.get_unit_location (&2).is_none ());
+    }
+
+    #[test]
+    fn game_forecast_magic () {
+        let mut game = generate_game ();
+
+        game.init ().unwrap ();
+
+        // Test This magic
+        let forecast = game.forecast_magic (0, 0, &[]);
+        let user = forecast.get_user ();
+
+        assert! (user.get_loss (HLT) > 0);
+        assert! (user.get_loss (SPL) > 0);
+        assert! (user.get_loss (ORG) > 0);
+        assert_eq! (user.get_appliables (), &[AppliableKind::Modifier (4)]);
+        assert! (forecast.get_targets ().is_empty ());
+        // Test Map magic
+        let forecast = game.forecast_magic (0, 3, &[(1, 0)]);
+        let target = forecast.get_target (&2).unwrap ();
+
+        assert! (target.get_loss (HLT) > 0);
+        assert_eq! (target.get_appliables ().len (), 1);
+        assert! (game.grid.try_yield_appliable (&(1, 0)).is_none ());
+    }
+
     
     #[test]
     fn game_wait_unit () {
@@ -1527,6 +2881,105 @@ mod tests {
         // TODO: Surely there will be more later
     }
 
+    #[test]
+    fn game_kill_unit_leader () {
+        let mut game = generate_game ();
+        let attribute_11 = *game.scene.get_attribute (&11);
+
+        game.init ().unwrap ();
+        game.try_spawn_recruit (0);
+        game.send_passive (0);
+        game.units[0].add_appliable (Box::new (attribute_11));
+        assert_eq! (game.grid.get_unit_location (&1), Some (&(0, 1)));
+        assert! (game.units[1].get_skill_passive_id ().is_some ());
+
+        // Test death attribute and succession
+        game.kill_unit (0);
+        assert! (game.grid.get_unit_location (&0).is_none ());
+        assert_eq! (game.units[1].get_statistic (DEF).0, 18);
+        assert_eq! (game.units[2].get_statistic (DEF).0, 20);
+        assert_eq! (game.units[1].get_statistic (MRL).0, 900);
+        assert_eq! (game.units[1].get_leader_id (), 1);
+        assert_eq! (game.units[1].get_skill_passive_id (), Some (1));
+        assert! (game.factions[0].get_followers (&1).contains (&1));
+        // Test eliminated faction
+        assert! (!game.scripts_fired[2]);
+        game.kill_unit (2);
+        assert! (game.scripts_fired[2]);
+    }
+
+    #[test]
+    fn game_kill_unit_order () {
+        let scene = Scene::default ();
+        let (sender, receiver) = mpsc::channel ();
+        let mut game = Game::new (scene, sender);
+
+        game.init ().unwrap ();
+        while receiver.try_recv ().is_ok () {}
+
+        // Test elimination is checked after scripts
+        let responses = game.handler.notify (Message::UnitDied (2));
+        assert! (matches! (responses.first (), Some (Response::ApplyDeath (2))));
+        assert! (matches! (responses.last (), Some (Response::CheckElimination (2))));
+        // Test death is logged once, before elimination
+        game.kill_unit (2);
+        let messages: Vec<String> = receiver.try_iter ().collect ();
+        assert_eq! (messages, vec![String::from ("2 died"), String::from ("The enemy is routed")]);
+    }
+
+    #[test]
+    fn game_reroute_followers () {
+        let mut game = generate_game ();
+
+        game.init ().unwrap ();
+        game.try_spawn_recruit (0);
+        game.place_unit (3, (1, 1));
+        game.factions[0].add_follower (3, 0);
+        game.units[3].set_leader_id (0);
+        game.send_passive (0);
+
+        // Test heir succession
+        game.kill_unit (0);
+        assert_eq! (game.units[3].get_leader_id (), 3);
+        assert_eq! (game.units[1].get_leader_id (), 3);
+        assert! (game.factions[0].get_followers (&3).contains (&1));
+        assert_eq! (game.units[1].get_statistic (MRL).0, 900);
+        assert_eq! (game.units[3].get_statistic (MRL).0, 900);
+        // Test organisation succession
+        game.units[1].set_statistic (ORG, 10);
+        game.kill_unit (3);
+        assert_eq! (game.units[1].get_leader_id (), 1);
+        assert_eq! (game.units[1].get_skill_passive_id (), Some (1));
+        assert_eq! (game.units[1].get_statistic (MRL).0, 800);
+    }
+
+    #[test]
+    fn game_gain_experience () {
+        let mut game = generate_game ();
+
+        game.gain_experience (1, 200);
+        assert_eq! (game.units[1].get_level (), 2);
+        assert_eq! (game.units[1].get_class_id (), 4);
+        // Test promotion
+        game.gain_experience (1, 100);
+        assert_eq! (game.units[1].get_level (), 3);
+        assert_eq! (game.units[1].get_class_id (), 2);
+        assert_eq! (game.units[1].get_magic_ids (), &[0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn game_switch_weapon () {
+        let mut game = generate_game ();
+
+        game.switch_weapon (3);
+        assert_eq! (game.units[3].get_class_id (), 0);
+        // Test weapon promotion
+        game.units[2].change_class (0);
+        game.switch_weapon (2);
+        assert_eq! (game.units[2].get_weapon ().get_id (), 1);
+        assert_eq! (game.units[2].get_class_id (), 3);
+    }
+
     #[test]
     fn game_filter_unit_allegiance () {
         let game = generate_game ();
@@ -1655,9 +3108,9 @@ mod tests {
     #[test]
     fn game_start_turn () {
         let mut game = generate_game ();
-        let modifier_9 = *game.scene.get_modifier (&9);
+        let modifier_9 = game.scene.get_modifier (&9).clone ();
         let mut modifier_9 = Box::new (modifier_9);
-        let modifier_4 = *game.scene.get_modifier (&4);
+        let modifier_4 = game.scene.get_modifier (&4).clone ();
         let modifier_4 = Box::new (modifier_4);
 
         game.grid.place_unit (0, (1, 1));
@@ -1743,7 +3196,8 @@ mod tests {
         assert_eq! (controlled_0_0, controlled_0_1);
         assert! (mrl_1_0 < mrl_1_1);
         assert_eq! (hlt_1_0, hlt_1_1);
-        assert_eq! (spl_1_0, spl_1_1);
+        // Test terrain drain
+        assert_eq! (spl_1_0 - game.scene.get_terrain (&1).get_drain_spl (), spl_1_1);
         // Test normal end
         let mrl_0_0 = game.units[0].get_statistic (MRL).0;
         let hlt_0_0 = game.units[0].get_statistic (HLT).0;
@@ -1759,6 +3213,168 @@ mod tests {
         assert! (spl_0_0 < spl_0_1);
     }
 
+    #[test]
+    fn game_find_threats () {
+        let mut game = generate_game ();
+
+        game.init ().unwrap ();
+
+        let threat_map = game.find_threats (0, None);
+
+        assert_eq! (threat_map.locations_iter ().count (), 6);
+        assert_eq! (threat_map.get_unit_ids (&(0, 2)), &[2]);
+        assert_eq! (game.find_threats (0, Some (0)).locations_iter ().count (), 6);
+        let threat_map = game.find_threats (1, None);
+
+        assert_eq! (threat_map.get_unit_ids (&(0, 2)), &[0]);
+        // Test hidden units
+        game.grid.remove_unit (&2);
+        game.grid.place_unit (2, (0, 2));
+        game.update_visibilities ();
+        assert! (game.find_threats (0, None).locations_iter ().count () > 0);
+        assert_eq! (game.find_threats (0, Some (0)).locations_iter ().count (), 0);
+        // Test dead units
+        game.kill_unit (2);
+
+        let threat_map = game.find_threats (0, None);
+
+        assert_eq! (threat_map.locations_iter ().count (), 0);
+    }
+
+    #[test]
+    fn game_run_script () {
+        let mut game = generate_game ();
+
+        game.init ().unwrap ();
+        assert! (game.factions[0].is_ally (&2));
+        // Test captured city
+        game.units[2].change_class (1); // ignore zones of control
+        game.movements = vec![Direction::Right, Direction::Up, Direction::Right];
+        game.move_unit (2);
+        assert! (game.scripts_fired[1]);
+        assert! (!game.factions[0].is_ally (&2));
+        assert! (!game.factions[2].is_ally (&0));
+        assert! (game.grid.get_modifier (&(0, 2)).is_some ());
+        assert! (game.grid.get_modifier (&(0, 1)).is_some ());
+        assert! (game.grid.get_modifier (&(1, 0)).is_none ());
+        // Test reached turn
+        game.notify (Message::TurnReached (24));
+        assert! (game.grid.get_unit_location (&4).is_none ());
+        game.notify (Message::TurnReached (25));
+        assert_eq! (game.grid.get_unit_location (&4), Some (&(0, 1)));
+        // Test non-repeatable script
+        game.grid.remove_unit (&4);
+        game.notify (Message::TurnReached (25));
+        assert! (game.grid.get_unit_location (&4).is_none ());
+    }
+
+    #[test]
+    fn game_update_weather () {
+        let mut game = generate_game ();
+
+        game.init ().unwrap ();
+        assert_eq! (game.get_weather ().unwrap ().get_kind (), WeatherKind::Clear);
+        game.number_turns = 10;
+        assert_eq! (game.forecast_weather (3), vec![WeatherKind::Clear, WeatherKind::Rain, WeatherKind::Rain]);
+        // Test exposed unit
+        let mov_2 = game.units[2].get_statistic (MOV).0;
+
+        game.grid.remove_unit (&2);
+        game.grid.place_unit (2, (1, 1));
+        game.number_turns = 12;
+        game.update_weather ();
+        assert_eq! (game.get_weather ().unwrap ().get_kind (), WeatherKind::Rain);
+        assert_eq! (game.grid.get_cost (&(0, 0), Direction::Right), 3);
+        assert_eq! (game.units[2].get_statistic (MOV).0, mov_2 * 9 / 10);
+        // Test sheltered unit
+        let mov_0 = game.units[0].get_statistic (MOV).0;
+
+        game.update_weather ();
+        assert_eq! (game.units[0].get_statistic (MOV).0, mov_0);
+        assert_eq! (game.units[2].get_statistic (MOV).0, mov_2 * 9 / 10);
+        // Test changed weather
+        game.number_turns = 20;
+        game.update_weather ();
+        assert_eq! (game.get_weather ().unwrap ().get_kind (), WeatherKind::Clear);
+        assert_eq! (game.grid.get_cost (&(0, 0), Direction::Right), 2);
+        assert_eq! (game.units[2].get_statistic (MOV).0, mov_2);
+    }
+
+    #[test]
+    fn game_update_visibilities () {
+        let mut game = generate_game ();
+
+        game.init ().unwrap ();
+        assert! (game.is_visible (0, &(1, 0)));
+        assert! (!game.is_visible (0, &(0, 2)));
+        assert_eq! (game.factions[0].get_unit_location_seen (&2), Some (&(1, 0)));
+        assert_eq! (game.find_units_range (0, Target::Enemy, Area::Single, 2), vec![2]);
+        // Test hidden enemy
+        game.grid.remove_unit (&2);
+        game.grid.place_unit (2, (0, 2));
+        game.update_visibilities ();
+        assert_eq! (game.factions[0].get_unit_location_seen (&2), None);
+        assert! (game.find_units_range (0, Target::Enemy, Area::Single, 2).is_empty ());
+        // Test render context
+        let context = game.get_render_context (Some (0));
+
+        assert_eq! (context.get_unit_locations ()[2], None);
+        assert! (!context.is_visible (&(0, 2)));
+        assert_eq! (game.get_render_context (None).get_unit_locations ()[2], Some ((0, 2)));
+        // Test dead enemy
+        game.grid.remove_unit (&2);
+        game.grid.place_unit (2, (1, 0));
+        game.update_visibilities ();
+        assert_eq! (game.factions[0].get_unit_location_seen (&2), Some (&(1, 0)));
+        game.kill_unit (2);
+        assert_eq! (game.factions[0].get_unit_location_seen (&2), None);
+    }
+
+    #[test]
+    fn game_act_move () {
+        let mut game = generate_game ();
+
+        game.init ().unwrap ();
+        game.update (Command::Action (Action::Move));
+        // Test unreachable location
+        game.update (Command::Location ((1, 2)));
+        assert! (game.movements.is_empty ());
+        // Test path to location
+        let mov = game.mov;
+
+        game.update (Command::Location ((0, 2)));
+        assert_eq! (game.movements, vec![Direction::Right, Direction::Right]);
+        assert_eq! (game.mov, mov - 4);
+        game.update (Command::Confirm);
+        assert_eq! (game.grid.get_unit_location (&0).unwrap (), &(0, 2));
+    }
+
+    #[test]
+    fn game_act_attack () {
+        let mut game = generate_game ();
+        let mut prompted = generate_game ();
+
+        game.init ().unwrap ();
+        prompted.init ().unwrap ();
+        // Test invalid weapon
+        assert_eq! (game.update (Command::Attack (2, 1)), Progress::Ongoing);
+        assert! (matches! (game.state, State::Idle));
+        // Test invalid target
+        assert_eq! (game.update (Command::Attack (1, 0)), Progress::Ongoing);
+        assert! (matches! (game.state, State::Idle));
+        // Test specified attack
+        let hlt_2 = game.units[2].get_statistic (HLT).0;
+
+        assert_eq! (game.update (Command::Attack (2, 0)), Progress::TurnOver);
+        assert! (game.units[2].get_statistic (HLT).0 < hlt_2);
+        assert_eq! (prompted.update (Command::Action (Action::Attack)), Progress::Ongoing);
+        assert_eq! (prompted.update (Command::Confirm), Progress::Ongoing);
+        assert_eq! (prompted.update (Command::Confirm), Progress::TurnOver);
+        assert_eq! (game.units[2].get_statistic (HLT), prompted.units[2].get_statistic (HLT));
+        assert_eq! (game.units[2].get_statistic (MRL), prompted.units[2].get_statistic (MRL));
+        assert_eq! (game.units[0].get_statistic (HLT), prompted.units[0].get_statistic (HLT));
+    }
+
     #[test]
     fn game_update_turns () {
         let mut game = generate_game ();
@@ -1812,6 +3428,112 @@ mod tests {
         assert_eq! (turn.get_delay (), 5);
     }
 
+    #[test]
+    fn game_save_load () {
+        let mut game = generate_game ();
+        let modifier_4 = game.scene.get_modifier (&4).clone ();
+
+        game.init ().unwrap ();
+        game.units[0].set_statistic (HLT, 500);
+        game.units[2].add_appliable (Box::new (modifier_4));
+        game.units[0].switch_weapon ();
+        game.units[0].gain_experience (150);
+        game.end_turn (0);
+        game.number_turns = 3;
+
+        let save: Save = ron::from_str (&ron::to_string (&game.save ()).unwrap ()).unwrap ();
+        let mut loaded = generate_game ();
+
+        loaded.load (&save).unwrap ();
+        assert_eq! (loaded.number_turns, 3);
+        assert_eq! (loaded.units[0].get_statistic (HLT), game.units[0].get_statistic (HLT));
+        assert_eq! (loaded.units[2].get_statistic (ATK), game.units[2].get_statistic (ATK));
+        assert_eq! (loaded.units[2].get_statistic (DEF), game.units[2].get_statistic (DEF));
+        assert_eq! (loaded.units[0].get_weapon ().get_id (), game.units[0].get_weapon ().get_id ());
+        assert_eq! (loaded.units[0].get_level (), 1);
+        assert_eq! (loaded.units[0].get_experience (), 50);
+        assert_eq! (loaded.grid.get_unit_location (&0), game.grid.get_unit_location (&0));
+        assert_eq! (loaded.grid.get_unit_location (&2), game.grid.get_unit_location (&2));
+        assert_eq! (loaded.grid.get_faction_locations (&0), game.grid.get_faction_locations (&0));
+        assert_eq! (loaded.factions[0].get_followers (&0), game.factions[0].get_followers (&0));
+
+        while let Some (turn) = game.turns.pop () {
+            let turn_loaded = loaded.turns.pop ().unwrap ();
+
+            assert_eq! (turn.get_unit_id (), turn_loaded.get_unit_id ());
+            assert_eq! (turn.get_delay (), turn_loaded.get_delay ());
+        }
+
+        assert! (loaded.turns.is_empty ());
+        // Test scene is relinked
+        assert! (Rc::ptr_eq (loaded.grid.get_scene (), &loaded.scene));
+        assert! (Rc::ptr_eq (loaded.grid.get_tile (&(0, 0)).get_scene (), &loaded.scene));
+        assert! (loaded.units.iter ().all (|u| Rc::ptr_eq (u.get_scene (), &loaded.scene)));
+        assert! (!Rc::ptr_eq (&loaded.scene, &game.scene));
+
+        // Test save without holds
+        let holds = format! ("holds:{},", ron::to_string (&game.holds).unwrap ());
+        let save = ron::to_string (&game.save ()).unwrap ();
+        assert! (save.contains (&holds));
+        let save: Save = ron::from_str (&save.replace (&holds, "")).unwrap ();
+        let mut loaded = generate_game ();
+
+        loaded.load (&save).unwrap ();
+        assert_eq! (loaded.holds, game.holds);
+
+        // Test invalid scene
+        let retaliation = format! ("retaliation:{}", ron::to_string (game.scene.get_retaliation ()).unwrap ());
+        let retaliation_invalid = format! ("retaliation:{}", ron::to_string (&Retaliation::new (RetaliationOrder::After, 150)).unwrap ());
+        let save = ron::to_string (&game.save ()).unwrap ();
+        assert! (save.contains (&retaliation));
+        let save: Save = ron::from_str (&save.replace (&retaliation, &retaliation_invalid)).unwrap ();
+        let mut loaded = generate_game ();
+
+        loaded.init ().unwrap ();
+        assert! (loaded.load (&save).is_err ());
+        assert_eq! (loaded.number_turns, 0);
+    }
+
+    #[test]
+    fn game_find_outcome () {
+        let mut game = generate_game ();
+
+        // Test empty grid
+        assert_eq! (game.find_outcome (), Some (GameOutcome::Draw));
+        game.init ().unwrap ();
+        assert_eq! (game.find_units_alive (), vec![0, 2]);
+        assert_eq! (game.find_outcome (), None);
+        // Test only allies remaining
+        game.kill_unit (2);
+        assert_eq! (game.find_units_alive (), vec![0]);
+        assert_eq! (game.find_outcome (), Some (GameOutcome::Victory (0)));
+        assert_eq! (game.update (Command::Action (Action::Wait)), Progress::GameOver (GameOutcome::Victory (0)));
+    }
+
+    #[test]
+    fn game_is_complete () {
+        let mut game = generate_game ();
+
+        game.init ().unwrap ();
+        // Test assassinate
+        assert! (!game.is_complete (2, 1, &Objective::Assassinate (0)));
+        game.kill_unit (0);
+        assert! (game.is_complete (2, 1, &Objective::Assassinate (0)));
+        // Test survive
+        assert! (!game.is_complete (0, 1, &Objective::Survive (2)));
+        game.number_turns = 2;
+        assert! (game.is_complete (0, 1, &Objective::Survive (2)));
+        assert! (!game.is_complete (0, 0, &Objective::Survive (2)));
+        // Test control
+        assert! (game.is_complete (0, 1, &Objective::Control (0)));
+        assert! (!game.is_complete (0, 1, &Objective::Control (50)));
+        // Test hold
+        assert! (!game.is_complete (0, 1, &Objective::Hold (vec![2], 1)));
+        game.holds[0] = Some (1);
+        assert! (game.is_complete (0, 1, &Objective::Hold (vec![2], 1)));
+        assert! (!game.is_complete (0, 1, &Objective::Hold (vec![2], 2)));
+    }
+
     // #[test]
     // fn game_do_turn () {
     // let mut game = generate_game (&b""[..]);
@@ -1,11 +1,21 @@
+mod command;
+pub use self::command::*;
+mod decision;
+pub use self::decision::*;
+mod forecast;
+pub use self::forecast::*;
 mod game;
 pub use self::game::*;
 mod logger;
 pub use self::logger::*;
 mod renderer;
 pub use self::renderer::*;
+mod replay;
+pub use self::replay::*;
 mod save;
 pub use self::save::*;
+mod simulation;
+pub use self::simulation::*;
 mod turn;
 pub use self::turn::*;
 mod validator;
@@ -1,23 +1,45 @@
-use crate::common::{ID, Scene};
-use crate::map::Location;
+use crate::common::ID;
+#[cfg (feature = "gui")]
+use crate::common::Scene;
+use crate::map::{Location, Reachability, ThreatMap};
+use std::collections::HashSet;
+#[cfg (feature = "gui")]
+use sdl2::pixels::Color;
+#[cfg (feature = "gui")]
 use sdl2::image::LoadTexture;
+#[cfg (feature = "gui")]
 use sdl2::rect::Rect;
-use sdl2::render::{Canvas, Texture, TextureCreator};
+#[cfg (feature = "gui")]
+use sdl2::render::{BlendMode, Canvas, Texture, TextureCreator};
+#[cfg (feature = "gui")]
 use sdl2::video::{Window, WindowContext};
+#[cfg (feature = "gui")]
 use std::error::Error;
+#[cfg (feature = "gui")]
 use std::path::Path;
 
+#[cfg (feature = "gui")]
 const TILE_LENGTH: u32 = 16;
+#[cfg (feature = "gui")]
 const TILE_HEIGHT: u32 = 16;
+#[cfg (feature = "gui")]
+const COLOUR_REACHABLE: Color = Color::RGBA (0, 0, 255, 96);
+#[cfg (feature = "gui")]
+const COLOUR_THREATENED: Color = Color::RGBA (255, 0, 0, 96);
+#[cfg (feature = "gui")]
+const COLOUR_FOG: Color = Color::RGBA (0, 0, 0, 160);
 
 pub struct RenderContext {
     terrains: Vec<Vec<ID>>,
     unit_locations: Vec<Option<Location>>,
+    reachability: Option<Reachability>, // only while moving
+    threat_map: Option<ThreatMap>, // only while moving
+    locations_visible: Option<HashSet<Location>>, // None -> everything is visible
 }
 
 impl RenderContext {
-    pub fn new (terrains: Vec<Vec<ID>>, unit_locations: Vec<Option<Location>>) -> Self {
-        Self { terrains, unit_locations }
+    pub fn new (terrains: Vec<Vec<ID>>, unit_locations: Vec<Option<Location>>, reachability: Option<Reachability>, threat_map: Option<ThreatMap>, locations_visible: Option<HashSet<Location>>) -> Self {
+        Self { terrains, unit_locations, reachability, threat_map, locations_visible }
     }
 
     pub fn get_terrains (&self) -> &[Vec<ID>] {
@@ -27,13 +49,27 @@ impl RenderContext {
     pub fn get_unit_locations (&self) -> &[Option<Location>] {
         &self.unit_locations
     }
+
+    pub fn get_reachability (&self) -> Option<&Reachability> {
+        self.reachability.as_ref ()
+    }
+
+    pub fn get_threat_map (&self) -> Option<&ThreatMap> {
+        self.threat_map.as_ref ()
+    }
+
+    pub fn is_visible (&self, location: &Location) -> bool {
+        self.locations_visible.as_ref ().is_none_or (|l: &HashSet<Location>| l.contains (location))
+    }
 }
 
+#[cfg (feature = "gui")]
 pub struct Renderer {
     terrains: Vec<Texture>,
     units: Vec<Texture>,
 }
 
+#[cfg (feature = "gui")]
 impl Renderer {
     pub fn new (texture_creator: &TextureCreator<WindowContext>, scene: &Scene) -> Result<Renderer, Box<dyn Error>> {
         let mut terrains: Vec<Texture> = Vec::new ();
@@ -73,6 +109,30 @@ impl Renderer {
             }
         }
 
+        canvas.set_blend_mode (BlendMode::Blend);
+
+        if let Some (reachability) = context.get_reachability () {
+            canvas.set_draw_color (COLOUR_REACHABLE);
+
+            for location in reachability.locations_iter () {
+                let x: i32 = (location.1 as i32) * (TILE_HEIGHT as i32);
+                let y: i32 = (location.0 as i32) * (TILE_LENGTH as i32);
+
+                canvas.fill_rect (Rect::new (x, y, TILE_LENGTH, TILE_HEIGHT)).unwrap ();
+            }
+        }
+
+        if let Some (threat_map) = context.get_threat_map () {
+            canvas.set_draw_color (COLOUR_THREATENED);
+
+            for location in threat_map.locations_iter () {
+                let x: i32 = (location.1 as i32) * (TILE_HEIGHT as i32);
+                let y: i32 = (location.0 as i32) * (TILE_LENGTH as i32);
+
+                canvas.fill_rect (Rect::new (x, y, TILE_LENGTH, TILE_HEIGHT)).unwrap ();
+            }
+        }
+
         for (unit_id, location) in context.get_unit_locations ().iter ().enumerate () {
             if let Some (location) = location {
                 let x: i32 = (location.1 as i32) * (TILE_HEIGHT as i32);
@@ -81,5 +141,18 @@ impl Renderer {
                 canvas.copy (&self.units[unit_id], None, destination).unwrap ();
             }
         }
+
+        canvas.set_draw_color (COLOUR_FOG);
+
+        for (i, row) in context.get_terrains ().iter ().enumerate () {
+            for j in 0 .. row.len () {
+                if !context.is_visible (&(i, j)) {
+                    let x: i32 = (j as i32) * (TILE_HEIGHT as i32);
+                    let y: i32 = (i as i32) * (TILE_LENGTH as i32);
+
+                    canvas.fill_rect (Rect::new (x, y, TILE_LENGTH, TILE_HEIGHT)).unwrap ();
+                }
+            }
+        }
     }
 }
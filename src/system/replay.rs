@@ -0,0 +1,117 @@
+use super::{Command, Game};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+#[derive (Debug)]
+#[derive (Deserialize, Serialize)]
+pub struct Replay {
+    scene_hash: u64,
+    inputs: Vec<Command>,
+}
+
+impl Replay {
+    pub fn new (scene_hash: u64) -> Self {
+        let inputs: Vec<Command> = Vec::new ();
+
+        Self { scene_hash, inputs }
+    }
+
+    pub fn load<P: AsRef<Path>> (path: P) -> Result<Self, Box<dyn Error>> {
+        let contents: String = fs::read_to_string (path)?;
+        let replay: Replay = ron::from_str (&contents)?;
+
+        Ok (replay)
+    }
+
+    pub fn store<P: AsRef<Path>> (&self, path: P) -> Result<(), Box<dyn Error>> {
+        let contents: String = ron::ser::to_string_pretty (self, ron::ser::PrettyConfig::default ())?;
+
+        fs::write (path, contents)?;
+
+        Ok (())
+    }
+
+    pub fn record (&mut self, input: Command) {
+        self.inputs.push (input);
+    }
+
+    pub fn play (&self, game: &mut Game) -> Result<(), Box<dyn Error>> {
+        let scene_hash: u64 = game.get_scene ().calculate_hash ();
+
+        if scene_hash != self.scene_hash {
+            return Err (Box::from (format! ("Scene hash {} does not match replay scene hash {}", scene_hash, self.scene_hash)))
+        }
+
+        for input in self.inputs.iter () {
+            game.update (*input);
+        }
+
+        Ok (())
+    }
+
+    pub fn get_scene_hash (&self) -> u64 {
+        self.scene_hash
+    }
+
+    pub fn len (&self) -> usize {
+        self.inputs.len ()
+    }
+
+    pub fn is_empty (&self) -> bool {
+        self.inputs.is_empty ()
+    }
+}
+
+#[cfg (test)]
+mod tests {
+    use super::*;
+    use crate::character::UnitStatistic;
+    use crate::map::Direction;
+    use crate::system::Action;
+    use crate::tests::generate_game;
+
+    #[test]
+    fn replay_play () {
+        let mut game = generate_game ();
+        let mut replay = Replay::new (game.get_scene ().calculate_hash ());
+        let inputs = [
+            Command::Action (Action::Move), Command::Direction (Direction::Right), Command::Confirm,
+            Command::Action (Action::Attack), Command::Confirm, Command::Confirm,
+            Command::Action (Action::Move), Command::Direction (Direction::Down), Command::Confirm, Command::Action (Action::Wait),
+            Command::Action (Action::Weapon), Command::Action (Action::Wait),
+        ];
+
+        for input in inputs {
+            replay.record (input);
+            game.update (input);
+        }
+
+        let replay: Replay = ron::from_str (&ron::to_string (&replay).unwrap ()).unwrap ();
+        let mut replayed = generate_game ();
+
+        assert_eq! (replay.len (), inputs.len ());
+        replay.play (&mut replayed).unwrap ();
+
+        let save = game.save ();
+        let save_replayed = replayed.save ();
+
+        for (unit, unit_replayed) in save.get_units ().iter ().zip (save_replayed.get_units ().iter ()) {
+            let unit_id = unit.get_id ();
+
+            assert_eq! (unit.get_statistic (UnitStatistic::MRL), unit_replayed.get_statistic (UnitStatistic::MRL));
+            assert_eq! (unit.get_statistic (UnitStatistic::HLT), unit_replayed.get_statistic (UnitStatistic::HLT));
+            assert_eq! (unit.get_statistic (UnitStatistic::SPL), unit_replayed.get_statistic (UnitStatistic::SPL));
+            assert_eq! (save.get_grid ().get_unit_location (&unit_id), save_replayed.get_grid ().get_unit_location (&unit_id));
+        }
+
+        assert! (save.get_number_turns () > 0);
+        assert_eq! (save.get_number_turns (), save_replayed.get_number_turns ());
+        // Test mismatched scene
+        let replay = Replay::new (0);
+        let mut replayed = generate_game ();
+
+        assert! (replay.play (&mut replayed).is_err ());
+    }
+}
@@ -1,7 +1,88 @@
-use serde::{Serialize, Deserialize};
+use super::Turn;
+use crate::character::{Faction, Unit};
+use crate::common::Scene;
+use crate::map::Grid;
+use serde::{Deserialize, Serialize};
+use std::collections::BinaryHeap;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
 
-// TODO: Save file
-#[derive (Serialize, Deserialize)]
+#[derive (Debug)]
+#[derive (Deserialize, Serialize)]
 pub struct Save {
+    scene: Scene,
+    turn: Option<Turn>,
+    turns: BinaryHeap<Turn>,
+    number_turns: usize,
+    mov: u16,
+    grid: Grid,
+    units: Vec<Unit>,
+    factions: Vec<Faction>,
+    #[serde (default)]
+    holds: Vec<Option<usize>>,
+    #[serde (default)]
+    scripts_fired: Vec<bool>,
+}
+
+impl Save {
+    #[allow (clippy::too_many_arguments)]
+    pub fn new (scene: Scene, turn: Option<Turn>, turns: BinaryHeap<Turn>, number_turns: usize, mov: u16, grid: Grid, units: Vec<Unit>, factions: Vec<Faction>, holds: Vec<Option<usize>>, scripts_fired: Vec<bool>) -> Self {
+        Self { scene, turn, turns, number_turns, mov, grid, units, factions, holds, scripts_fired }
+    }
+
+    pub fn load<P: AsRef<Path>> (path: P) -> Result<Self, Box<dyn Error>> {
+        let contents: String = fs::read_to_string (path)?;
+        let save: Save = ron::from_str (&contents)?;
+
+        Ok (save)
+    }
+
+    pub fn store<P: AsRef<Path>> (&self, path: P) -> Result<(), Box<dyn Error>> {
+        let contents: String = ron::ser::to_string_pretty (self, ron::ser::PrettyConfig::default ())?;
+
+        fs::write (path, contents)?;
+
+        Ok (())
+    }
+
+    pub fn get_scene (&self) -> &Scene {
+        &self.scene
+    }
+
+    pub fn get_turn (&self) -> Option<&Turn> {
+        self.turn.as_ref ()
+    }
+
+    pub fn get_turns (&self) -> &BinaryHeap<Turn> {
+        &self.turns
+    }
+
+    pub fn get_number_turns (&self) -> usize {
+        self.number_turns
+    }
+
+    pub fn get_mov (&self) -> u16 {
+        self.mov
+    }
+
+    pub fn get_grid (&self) -> &Grid {
+        &self.grid
+    }
+
+    pub fn get_units (&self) -> &[Unit] {
+        &self.units
+    }
+
+    pub fn get_factions (&self) -> &[Faction] {
+        &self.factions
+    }
+
+    pub fn get_holds (&self) -> &[Option<usize>] {
+        &self.holds
+    }
 
+    pub fn get_scripts_fired (&self) -> &[bool] {
+        &self.scripts_fired
+    }
 }
@@ -0,0 +1,174 @@
+use super::{Decision, Game, GameOutcome, Replay};
+use crate::common::{ID, Scene};
+use crate::controller::Controller;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::sync::mpsc::Sender;
+
+#[derive (Debug)]
+pub struct Report {
+    number_turns: usize,
+    outcome: Option<GameOutcome>,
+    faction_unit_ids: Vec<Vec<ID>>,
+}
+
+impl Report {
+    pub fn new (number_turns: usize, outcome: Option<GameOutcome>, faction_unit_ids: Vec<Vec<ID>>) -> Self {
+        Self { number_turns, outcome, faction_unit_ids }
+    }
+
+    pub fn get_number_turns (&self) -> usize {
+        self.number_turns
+    }
+
+    pub fn get_outcome (&self) -> Option<GameOutcome> {
+        self.outcome
+    }
+
+    pub fn get_unit_ids (&self, faction_id: &ID) -> &[ID] {
+        &self.faction_unit_ids[*faction_id]
+    }
+}
+
+impl Display for Report {
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some (outcome) = self.outcome {
+            writeln! (f, "{} after {} turns", outcome, self.number_turns)?;
+        } else {
+            writeln! (f, "Battle unfinished after {} turns", self.number_turns)?;
+        }
+
+        for (faction_id, unit_ids) in self.faction_unit_ids.iter ().enumerate () {
+            writeln! (f, "Faction {}: {:?}", faction_id, unit_ids)?;
+        }
+
+        Ok (())
+    }
+}
+
+pub struct Simulation {
+    game: Game,
+    controllers: Vec<Box<dyn Controller>>,
+    number_turns_max: usize,
+}
+
+impl Simulation {
+    pub fn new (scene: Scene, sender: Sender<String>, controllers: Vec<Box<dyn Controller>>, number_turns_max: usize) -> Result<Self, Box<dyn Error>> {
+        let number_factions: usize = scene.faction_builders_iter ().count ();
+
+        if controllers.len () != number_factions {
+            return Err (Box::from (format! ("Expected {} controllers, found {}", number_factions, controllers.len ())))
+        }
+
+        let mut game: Game = Game::new (scene, sender);
+
+        game.set_quiet (true);
+        game.init ()?;
+
+        Ok (Self { game, controllers, number_turns_max })
+    }
+
+    fn play_turn (&mut self) {
+        let unit_id: ID = self.game.get_unit_id_active ();
+        let faction_id: ID = self.game.get_unit (&unit_id).get_faction_id ();
+        let decision: Decision = self.controllers[faction_id].decide (&self.game, unit_id);
+
+        decision.play (&mut self.game);
+    }
+
+    pub fn run (&mut self) -> Report {
+        while !self.game.is_over () && self.game.get_number_turns () < self.number_turns_max {
+            self.play_turn ();
+        }
+
+        Self::report (&self.game, self.controllers.len ())
+    }
+
+    // Replays are checked against the scene before any input is played
+    pub fn replay (scene: Scene, sender: Sender<String>, replay: &Replay) -> Result<Report, Box<dyn Error>> {
+        let number_factions: usize = scene.faction_builders_iter ().count ();
+        let mut game: Game = Game::new (scene, sender);
+
+        game.set_quiet (true);
+        game.init ()?;
+        replay.play (&mut game)?;
+
+        Ok (Self::report (&game, number_factions))
+    }
+
+    fn report (game: &Game, number_factions: usize) -> Report {
+        let mut faction_unit_ids: Vec<Vec<ID>> = vec![Vec::new (); number_factions];
+
+        for unit_id in game.find_units_alive () {
+            let faction_id: ID = game.get_unit (&unit_id).get_faction_id ();
+
+            faction_unit_ids[faction_id].push (unit_id);
+        }
+
+        Report::new (game.get_number_turns (), game.find_outcome (), faction_unit_ids)
+    }
+
+    pub fn get_game (&self) -> &Game {
+        &self.game
+    }
+}
+
+#[cfg (test)]
+mod tests {
+    use super::*;
+    use crate::controller::Inactive;
+    use crate::system::{Action, Command};
+    use std::sync::mpsc;
+
+    fn generate_simulation (number_turns_max: usize) -> Simulation {
+        let scene = Scene::default ();
+        let (sender, _) = mpsc::channel ();
+        let controllers: Vec<Box<dyn Controller>> = vec![Box::new (Inactive), Box::new (Inactive), Box::new (Inactive)];
+
+        Simulation::new (scene, sender, controllers, number_turns_max).unwrap ()
+    }
+
+    #[test]
+    fn simulation_new () {
+        let scene = Scene::default ();
+        let (sender, _) = mpsc::channel ();
+        let controllers: Vec<Box<dyn Controller>> = vec![Box::new (Inactive)];
+
+        // Test missing controllers
+        assert! (Simulation::new (scene, sender, controllers, 10).is_err ());
+    }
+
+    #[test]
+    fn simulation_run () {
+        let mut simulation = generate_simulation (10);
+        let report = simulation.run ();
+
+        assert_eq! (report.get_number_turns (), 10);
+        assert_eq! (report.get_outcome (), None);
+        assert_eq! (report.get_unit_ids (&0), &[0]);
+        assert_eq! (report.get_unit_ids (&1), &[2]);
+        assert! (report.get_unit_ids (&2).is_empty ());
+    }
+
+    #[test]
+    fn simulation_replay () {
+        let scene = Scene::default ();
+        let (sender, _) = mpsc::channel ();
+        let mut replay = Replay::new (scene.calculate_hash ());
+
+        replay.record (Command::Action (Action::Wait));
+        replay.record (Command::Action (Action::Wait));
+
+        let report = Simulation::replay (scene, sender, &replay).unwrap ();
+
+        assert_eq! (report.get_number_turns (), 2);
+        assert_eq! (report.get_outcome (), None);
+        assert_eq! (report.get_unit_ids (&0), &[0]);
+        // Test mismatched scene
+        let scene = Scene::default ();
+        let (sender, _) = mpsc::channel ();
+        let replay = Replay::new (0);
+
+        assert! (Simulation::replay (scene, sender, &replay).is_err ());
+    }
+}
@@ -1,7 +1,10 @@
 use crate::common::ID;
+use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 
 #[derive (Debug)]
+#[derive (Clone)]
+#[derive (Deserialize, Serialize)]
 pub struct Turn {
     unit_id: ID,
     delay: u16,